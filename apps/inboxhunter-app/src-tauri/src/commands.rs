@@ -16,10 +16,27 @@ use std::os::windows::process::CommandExt;
 #[cfg(windows)]
 const CREATE_NO_WINDOW: u32 = 0x08000000;
 
+/// Max number of recent log lines kept in memory for the "copy diagnostics" button.
+const LOG_HISTORY_CAPACITY: usize = 500;
+
 pub struct AppState {
-    pub db_path: Mutex<String>,
+    /// r2d2-pooled connections (WAL + busy_timeout set on acquire, see
+    /// `db::ConnectionCustomizer`) so the log reader thread and UI polling
+    /// can hit SQLite concurrently instead of contending over one connection.
+    pub db_pool: db::DbPool,
     pub bot_running: Mutex<bool>,
     pub bot_process: Mutex<Option<Child>>,
+    pub log_history: Mutex<std::collections::VecDeque<LogEvent>>,
+    /// Set when the child acks a `ControlMessage::Stop` over the control channel.
+    /// `stop_bot` only reports whether the ack arrived when it escalates; its
+    /// forced kill after the 10-second wait happens whether or not it did.
+    pub stop_acked: std::sync::atomic::AtomicBool,
+    /// Live counters backing the `/metrics` endpoint's log-derived gauges.
+    pub log_counters: crate::metrics::LogCounters,
+    /// In-memory search/filter text per page (e.g. "processed", "scraped"),
+    /// restored once via `get_stored_search_string` when the user navigates
+    /// back to a table view. Not persisted - a fresh process starts empty.
+    pub search_strings: Mutex<std::collections::HashMap<String, String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -70,6 +87,29 @@ pub struct BotConfig {
     #[serde(rename = "apiKeys")]
     pub api_keys: APIKeys,
     pub settings: Settings,
+    /// Where `submit_logs` files diagnostics. Absent on settings saved before
+    /// log sinks became configurable, so submission stays disabled until the
+    /// user picks a destination rather than falling back to any default.
+    #[serde(rename = "logSink", default)]
+    pub log_sink: Option<LogSinkConfig>,
+    /// Auto-update preferences. Absent on settings saved before the updater
+    /// existed, in which case `check_for_update` is skipped on startup until
+    /// the user opts in from the settings screen.
+    #[serde(rename = "updateSettings", default)]
+    pub update_settings: Option<UpdateSettings>,
+}
+
+/// Persisted alongside the rest of `BotConfig` in `settings.json` - there's
+/// no separate settings table in this app, so the updater's config rides the
+/// same file-based save/load path as everything else.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSettings {
+    /// URL returning an `UpdateManifest` JSON document for the configured channel.
+    #[serde(rename = "releaseEndpoint")]
+    pub release_endpoint: String,
+    pub channel: String,
+    #[serde(rename = "lastChecked")]
+    pub last_checked: Option<String>,
 }
 
 // ==================== NEW DATABASE TYPES ====================
@@ -113,6 +153,21 @@ pub struct ScrapedURL {
     pub advertiser: Option<String>,
     pub scraped_at: String,
     pub processed: bool,
+    /// JSON-encoded `LinkMetadata`, populated by `enrich_scraped_url` /
+    /// `enrich_all_pending`. `None` until a row has been enriched at least once.
+    pub metadata: Option<String>,
+}
+
+/// OpenGraph/Twitter-card preview data for a scraped URL, fetched by
+/// `enrich_scraped_url` and stored as JSON in `ScrapedURL::metadata` so the
+/// frontend can render a rich preview instead of a bare link.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LinkMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "siteName")]
+    pub site_name: Option<String>,
+    pub image: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -148,6 +203,26 @@ pub struct ApiCostSummary {
 pub struct LogEvent {
     pub level: String,
     pub message: String,
+    /// The parsed fields from a `StructuredLog` line, when the sidecar emitted
+    /// one - lets the UI group by module, show timestamps, and filter
+    /// reliably instead of re-deriving everything from `message`.
+    pub structured: Option<serde_json::Value>,
+}
+
+/// A structured NDJSON log line emitted by the Python sidecar, as an
+/// alternative to plain print-based logging. Unknown fields are preserved in
+/// `fields` rather than dropped, so the UI can surface whatever the sidecar
+/// decides to attach without a schema change on this side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StructuredLog {
+    level: String,
+    message: String,
+    #[serde(default)]
+    module: Option<String>,
+    #[serde(default)]
+    timestamp: Option<String>,
+    #[serde(flatten)]
+    fields: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Get the sidecar binary name for the current platform
@@ -173,13 +248,93 @@ fn get_sidecar_name() -> String {
     return "inboxhunter-automation".to_string();
 }
 
-/// Find the bundled sidecar binary (for production builds)
-/// The sidecar is placed in the automation folder which is bundled as a resource
-fn find_sidecar_binary(app: &AppHandle) -> Option<PathBuf> {
+/// Result of resolving the sidecar binary, recording which strategy found it
+/// so diagnostics can explain *why* a given binary was chosen.
+pub struct SidecarResolution {
+    pub path: PathBuf,
+    pub method: String,
+}
+
+/// The target-triple suffix (and, on Windows, the `.exe` extension) Tauri's
+/// bundler appends to each `bundle.externalBin` entry when it builds a
+/// sidecar. The config-driven name from `sidecar_name_from_config` takes its
+/// suffix from here; the hardcoded fallback in `get_sidecar_name` keeps its
+/// own per-target names, so a new target needs a match arm in both.
+fn target_triple_suffix() -> &'static str {
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return "-aarch64-apple-darwin";
+
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    return "-x86_64-apple-darwin";
+
+    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+    return "-x86_64-pc-windows-msvc.exe";
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    return "-x86_64-unknown-linux-gnu";
+
+    #[cfg(not(any(
+        all(target_os = "macos", target_arch = "aarch64"),
+        all(target_os = "macos", target_arch = "x86_64"),
+        all(target_os = "windows", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "x86_64")
+    )))]
+    return "";
+}
+
+/// Derives the sidecar's expected filename from `tauri.conf.json`'s
+/// `bundle.externalBin` entry (its basename plus the target-triple suffix),
+/// so a rename there is picked up automatically instead of requiring a
+/// matching edit to `get_sidecar_name`'s hardcoded product name. Returns
+/// `None` if `externalBin` isn't configured.
+fn sidecar_name_from_config(app: &AppHandle) -> Option<String> {
+    let base_name = app.config()
+        .tauri
+        .bundle
+        .external_bin
+        .as_ref()?
+        .first()?
+        .rsplit('/')
+        .next()?
+        .to_string();
+    Some(format!("{}{}", base_name, target_triple_suffix()))
+}
+
+/// Resolve the sidecar binary, preferring Tauri's own resource resolver.
+/// When `bundle.externalBin` is configured, the expected filename is read
+/// from it directly; otherwise this falls back to the hardcoded name
+/// `get_sidecar_name` returns. The manual per-platform path search
+/// (`find_sidecar_binary_manual`) is the fallback for layouts the resource
+/// resolver doesn't cover (e.g. dev builds run straight from
+/// `target/debug`); it has no `AppHandle` to read `externalBin` from; it
+/// always uses the hardcoded name.
+fn resolve_sidecar(app: &AppHandle) -> Option<SidecarResolution> {
+    let sidecar_name = sidecar_name_from_config(app).unwrap_or_else(get_sidecar_name);
+
+    if let Some(resource_dir) = app.path_resolver().resource_dir() {
+        let automation_sidecar = resource_dir.join("automation").join(&sidecar_name);
+        if automation_sidecar.exists() {
+            return Some(SidecarResolution { path: automation_sidecar, method: "tauri_resource_resolver".to_string() });
+        }
+
+        let sidecar_path = resource_dir.join(&sidecar_name);
+        if sidecar_path.exists() {
+            return Some(SidecarResolution { path: sidecar_path, method: "tauri_resource_resolver".to_string() });
+        }
+    }
+
+    find_sidecar_binary_manual().map(|path| SidecarResolution { path, method: "manual_search".to_string() })
+}
+
+/// Find the bundled sidecar binary by walking the per-platform candidate
+/// paths directly, for layouts the Tauri resource resolver doesn't cover.
+/// Takes no `AppHandle` since it never consults the resource resolver - this
+/// is also what headless mode calls to locate the sidecar without one.
+pub(crate) fn find_sidecar_binary_manual() -> Option<PathBuf> {
     let sidecar_name = get_sidecar_name();
     let exe_path = std::env::current_exe().ok()?;
-    
-    println!("🔍 Looking for sidecar binary: {}", sidecar_name);
+
+    log::info!("🔍 Looking for sidecar binary: {}", sidecar_name);
     
     #[cfg(target_os = "macos")]
     {
@@ -192,26 +347,26 @@ fn find_sidecar_binary(app: &AppHandle) -> Option<PathBuf> {
                     .join("_up_")
                     .join("automation")
                     .join(&sidecar_name);
-                println!("   Checking bundled automation: {:?}", automation_sidecar);
+                log::info!("   Checking bundled automation: {:?}", automation_sidecar);
                 if automation_sidecar.exists() {
-                    println!("   ✅ Found sidecar in bundled automation folder");
+                    log::info!("   ✅ Found sidecar in bundled automation folder");
                     return Some(automation_sidecar);
                 }
                 
                 // Also check Resources directly
                 let resources_path = contents_dir.join("Resources").join(&sidecar_name);
-                println!("   Checking Resources: {:?}", resources_path);
+                log::info!("   Checking Resources: {:?}", resources_path);
                 if resources_path.exists() {
-                    println!("   ✅ Found sidecar in Resources");
+                    log::info!("   ✅ Found sidecar in Resources");
                     return Some(resources_path);
                 }
             }
             
             // Check next to executable
             let sidecar_path = macos_dir.join(&sidecar_name);
-            println!("   Checking macOS dir: {:?}", sidecar_path);
+            log::info!("   Checking macOS dir: {:?}", sidecar_path);
             if sidecar_path.exists() {
-                println!("   ✅ Found sidecar next to exe");
+                log::info!("   ✅ Found sidecar next to exe");
                 return Some(sidecar_path);
             }
         }
@@ -220,60 +375,60 @@ fn find_sidecar_binary(app: &AppHandle) -> Option<PathBuf> {
     #[cfg(target_os = "windows")]
     {
         if let Some(exe_dir) = exe_path.parent() {
-            println!("   📁 Exe directory: {:?}", exe_dir);
+            log::info!("   📁 Exe directory: {:?}", exe_dir);
             
             // Check in automation folder next to exe
             let automation_sidecar = exe_dir.join("automation").join(&sidecar_name);
-            println!("   Checking: {:?}", automation_sidecar);
+            log::info!("   Checking: {:?}", automation_sidecar);
             if automation_sidecar.exists() {
-                println!("   ✅ Found sidecar in automation folder");
+                log::info!("   ✅ Found sidecar in automation folder");
                 return Some(automation_sidecar);
             }
             
             // Check in _up_/automation (Tauri resource pattern)
             let up_automation_sidecar = exe_dir.join("_up_").join("automation").join(&sidecar_name);
-            println!("   Checking: {:?}", up_automation_sidecar);
+            log::info!("   Checking: {:?}", up_automation_sidecar);
             if up_automation_sidecar.exists() {
-                println!("   ✅ Found sidecar in _up_/automation folder");
+                log::info!("   ✅ Found sidecar in _up_/automation folder");
                 return Some(up_automation_sidecar);
             }
             
             // Check in resources folder (Windows Tauri pattern)
             let resources_sidecar = exe_dir.join("resources").join("automation").join(&sidecar_name);
-            println!("   Checking: {:?}", resources_sidecar);
+            log::info!("   Checking: {:?}", resources_sidecar);
             if resources_sidecar.exists() {
-                println!("   ✅ Found sidecar in resources/automation folder");
+                log::info!("   ✅ Found sidecar in resources/automation folder");
                 return Some(resources_sidecar);
             }
             
             // Check in resources/_up_/automation
             let resources_up_sidecar = exe_dir.join("resources").join("_up_").join("automation").join(&sidecar_name);
-            println!("   Checking: {:?}", resources_up_sidecar);
+            log::info!("   Checking: {:?}", resources_up_sidecar);
             if resources_up_sidecar.exists() {
-                println!("   ✅ Found sidecar in resources/_up_/automation folder");
+                log::info!("   ✅ Found sidecar in resources/_up_/automation folder");
                 return Some(resources_up_sidecar);
             }
             
             let sidecar_path = exe_dir.join(&sidecar_name);
-            println!("   Checking: {:?}", sidecar_path);
+            log::info!("   Checking: {:?}", sidecar_path);
             if sidecar_path.exists() {
-                println!("   ✅ Found sidecar next to exe");
+                log::info!("   ✅ Found sidecar next to exe");
                 return Some(sidecar_path);
             }
 
             // Check for production name (Tauri bundles without platform suffix)
             let prod_sidecar = exe_dir.join("inboxhunter-automation.exe");
-            println!("   Checking production name: {:?}", prod_sidecar);
+            log::info!("   Checking production name: {:?}", prod_sidecar);
             if prod_sidecar.exists() {
-                println!("   ✅ Found sidecar (production name)");
+                log::info!("   ✅ Found sidecar (production name)");
                 return Some(prod_sidecar);
             }
 
             // List contents of exe_dir for debugging
-            println!("   📂 Contents of exe directory:");
+            log::info!("   📂 Contents of exe directory:");
             if let Ok(entries) = std::fs::read_dir(exe_dir) {
                 for entry in entries.flatten() {
-                    println!("      - {:?}", entry.file_name());
+                    log::info!("      - {:?}", entry.file_name());
                 }
             }
         }
@@ -285,60 +440,50 @@ fn find_sidecar_binary(app: &AppHandle) -> Option<PathBuf> {
             // Check in automation folder
             let automation_sidecar = exe_dir.join("automation").join(&sidecar_name);
             if automation_sidecar.exists() {
-                println!("   ✅ Found sidecar in automation folder");
+                log::info!("   ✅ Found sidecar in automation folder");
                 return Some(automation_sidecar);
             }
             
             // Check in _up_/automation (Tauri resource pattern)
             let up_automation_sidecar = exe_dir.join("_up_").join("automation").join(&sidecar_name);
             if up_automation_sidecar.exists() {
-                println!("   ✅ Found sidecar in _up_/automation folder");
+                log::info!("   ✅ Found sidecar in _up_/automation folder");
                 return Some(up_automation_sidecar);
             }
             
             let sidecar_path = exe_dir.join(&sidecar_name);
             if sidecar_path.exists() {
-                println!("   ✅ Found sidecar next to exe");
+                log::info!("   ✅ Found sidecar next to exe");
                 return Some(sidecar_path);
             }
 
             // Check for production name (Tauri bundles without platform suffix)
             let prod_sidecar = exe_dir.join("inboxhunter-automation");
             if prod_sidecar.exists() {
-                println!("   ✅ Found sidecar (production name)");
+                log::info!("   ✅ Found sidecar (production name)");
                 return Some(prod_sidecar);
             }
         }
     }
 
-    // Try Tauri's resource resolver
-    if let Some(resource_dir) = app.path_resolver().resource_dir() {
-        // Check automation subfolder
-        let automation_sidecar = resource_dir.join("automation").join(&sidecar_name);
-        println!("   Checking resource/automation: {:?}", automation_sidecar);
-        if automation_sidecar.exists() {
-            println!("   ✅ Found sidecar via Tauri resolver");
-            return Some(automation_sidecar);
-        }
-        
-        let sidecar_path = resource_dir.join(&sidecar_name);
-        println!("   Checking resource dir: {:?}", sidecar_path);
-        if sidecar_path.exists() {
-            println!("   ✅ Found sidecar in resource dir");
-            return Some(sidecar_path);
-        }
-    }
-    
-    println!("   ❌ Sidecar binary not found");
+    log::error!("   ❌ Sidecar binary not found");
     None
 }
 
-/// Get path to automation folder - checks multiple locations
-fn get_automation_path(app: &AppHandle) -> Option<PathBuf> {
+/// Resolve the sidecar binary's path alone, for call sites that don't need
+/// to know which strategy found it.
+fn find_sidecar_binary(app: &AppHandle) -> Option<PathBuf> {
+    resolve_sidecar(app).map(|resolution| resolution.path)
+}
+
+/// Get path to automation folder - checks multiple locations. Takes no
+/// `AppHandle` since every check is relative to the current executable;
+/// headless mode calls this directly for the same reason.
+pub(crate) fn get_automation_path() -> Option<PathBuf> {
     let exe_path = std::env::current_exe().ok()?;
 
-    println!("🔍 Looking for automation scripts...");
-    println!("   Executable: {:?}", exe_path);
+    log::info!("🔍 Looking for automation scripts...");
+    log::info!("   Executable: {:?}", exe_path);
 
     // Detect if we're in dev mode
     let is_dev_mode = exe_path
@@ -357,9 +502,9 @@ fn get_automation_path(app: &AppHandle) -> Option<PathBuf> {
 
         if let Some(root) = project_root {
             let automation_path = root.join("automation");
-            println!("   Checking source code path (dev mode): {:?}", automation_path);
+            log::info!("   Checking source code path (dev mode): {:?}", automation_path);
             if automation_path.exists() && automation_path.join("main.py").exists() {
-                println!("   ✅ Found source code automation folder (dev mode)");
+                log::info!("   ✅ Found source code automation folder (dev mode)");
                 return Some(automation_path);
             }
         }
@@ -372,17 +517,17 @@ fn get_automation_path(app: &AppHandle) -> Option<PathBuf> {
             if let Some(contents_dir) = macos_dir.parent() {
                 // Check Resources/_up_/automation (Tauri's relative path pattern)
                 let up_path = contents_dir.join("Resources").join("_up_").join("automation");
-                println!("   Checking macOS Resources/_up_: {:?}", up_path);
+                log::info!("   Checking macOS Resources/_up_: {:?}", up_path);
                 if up_path.exists() && up_path.join("main.py").exists() {
-                    println!("   ✅ Found automation in Resources/_up_");
+                    log::info!("   ✅ Found automation in Resources/_up_");
                     return Some(up_path);
                 }
 
                 // Check Resources/automation
                 let res_path = contents_dir.join("Resources").join("automation");
-                println!("   Checking macOS Resources: {:?}", res_path);
+                log::info!("   Checking macOS Resources: {:?}", res_path);
                 if res_path.exists() && res_path.join("main.py").exists() {
-                    println!("   ✅ Found automation in Resources");
+                    log::info!("   ✅ Found automation in Resources");
                     return Some(res_path);
                 }
             }
@@ -395,28 +540,28 @@ fn get_automation_path(app: &AppHandle) -> Option<PathBuf> {
             // Check _up_/automation
             let up_path = exe_dir.join("_up_").join("automation");
             if up_path.exists() && up_path.join("main.py").exists() {
-                println!("   ✅ Found automation in _up_");
+                log::info!("   ✅ Found automation in _up_");
                 return Some(up_path);
             }
 
             // Check resources/automation
             let resources_path = exe_dir.join("resources").join("automation");
             if resources_path.exists() && resources_path.join("main.py").exists() {
-                println!("   ✅ Found automation in resources");
+                log::info!("   ✅ Found automation in resources");
                 return Some(resources_path);
             }
 
             // Check resources/_up_/automation
             let resources_up_path = exe_dir.join("resources").join("_up_").join("automation");
             if resources_up_path.exists() && resources_up_path.join("main.py").exists() {
-                println!("   ✅ Found automation in resources/_up_");
+                log::info!("   ✅ Found automation in resources/_up_");
                 return Some(resources_up_path);
             }
 
             // Check automation directly
             let direct_path = exe_dir.join("automation");
             if direct_path.exists() && direct_path.join("main.py").exists() {
-                println!("   ✅ Found automation next to exe");
+                log::info!("   ✅ Found automation next to exe");
                 return Some(direct_path);
             }
         }
@@ -428,14 +573,14 @@ fn get_automation_path(app: &AppHandle) -> Option<PathBuf> {
             // Check _up_/automation
             let up_path = exe_dir.join("_up_").join("automation");
             if up_path.exists() && up_path.join("main.py").exists() {
-                println!("   ✅ Found automation in _up_");
+                log::info!("   ✅ Found automation in _up_");
                 return Some(up_path);
             }
 
             // Check automation directly
             let direct_path = exe_dir.join("automation");
             if direct_path.exists() && direct_path.join("main.py").exists() {
-                println!("   ✅ Found automation next to exe");
+                log::info!("   ✅ Found automation next to exe");
                 return Some(direct_path);
             }
         }
@@ -445,118 +590,551 @@ fn get_automation_path(app: &AppHandle) -> Option<PathBuf> {
     if let Some(resource_path) = app.path_resolver().resolve_resource("automation/main.py") {
         if resource_path.exists() {
             if let Some(automation_dir) = resource_path.parent() {
-                println!("   ✅ Found automation via Tauri resolver: {:?}", automation_dir);
+                log::info!("   ✅ Found automation via Tauri resolver: {:?}", automation_dir);
                 return Some(automation_dir.to_path_buf());
             }
         }
     }
 
-    println!("   ❌ Could not find automation folder");
+    log::error!("   ❌ Could not find automation folder");
     None
 }
 
 /// Find Python with required packages installed
-fn find_dev_python(automation_path: &PathBuf) -> Option<String> {
-    println!("🐍 Looking for Python with packages...");
-    
-    // 1. Check for venv in the bundled/provided automation path
-    let venv_paths = [
-        automation_path.join("venv").join("bin").join("python"),
-        automation_path.join(".venv").join("bin").join("python"),
-        automation_path.join("venv").join("Scripts").join("python.exe"),
-        automation_path.join(".venv").join("Scripts").join("python.exe"),
-    ];
-    
-    for venv_python in &venv_paths {
-        println!("   Checking: {:?}", venv_python);
-        if venv_python.exists() {
-            println!("   ✅ Found venv Python: {:?}", venv_python);
-            return Some(venv_python.to_string_lossy().to_string());
-        }
-    }
-    
-    // 2. For bundled apps, check the source project venv location
-    // This handles the case where automation is bundled but venv is in source
-    let exe_path = std::env::current_exe().ok();
-    if let Some(exe) = exe_path {
-        // Try to find source project from executable path
-        // Pattern: .../src-tauri/target/release/bundle/macos/App.app/Contents/MacOS/app
-        let path_str = exe.to_string_lossy();
-        
-        // Check for both forward and backslashes (Windows uses backslashes)
-        let is_build_path = path_str.contains("target/release/bundle")
-            || path_str.contains("target\\release\\bundle")
-            || path_str.contains("target/debug")
-            || path_str.contains("target\\debug");
-
-        if is_build_path {
-            // We're running from a build - try to find source automation venv
-            // Look for /target/ or \target\ depending on platform
-            let target_pos = path_str.find("/target/")
-                .or_else(|| path_str.find("\\target\\"));
-
-            if let Some(target_pos) = target_pos {
-                // path_str[..target_pos] gives us .../src-tauri
-                // We need to go up one more level to get the actual project root
-                let src_tauri_path = PathBuf::from(&path_str[..target_pos]);
-                let project_root = src_tauri_path.parent(); // Go up from src-tauri to project root
-
-                if let Some(project_root) = project_root {
-                    let source_automation = project_root.join("automation");
-
-                    println!("   Checking source project: {:?}", source_automation);
-
-                    let source_venv_paths = [
-                        source_automation.join("venv").join("bin").join("python"),
-                        source_automation.join(".venv").join("bin").join("python"),
-                        source_automation.join("venv").join("Scripts").join("python.exe"),
-                        source_automation.join(".venv").join("Scripts").join("python.exe"),
-                    ];
-
-                    for venv_python in &source_venv_paths {
-                        if venv_python.exists() {
-                            println!("   ✅ Found source project venv: {:?}", venv_python);
-                            return Some(venv_python.to_string_lossy().to_string());
-                        }
-                    }
-                }
-            }
-        }
-    }
-    
-    // 3. Fallback to system Python (if packages are installed globally)
+/// Strategies 1-2: a venv already sitting next to the automation path, or
+/// (for a bundled app running from a build) one in the source project the
+/// build came from. Takes no `AppHandle` since every candidate is derived
+/// from `automation_path`/`current_exe` alone - headless mode calls this
+/// directly for the same reason.
+pub(crate) fn find_dev_venv_python(automation_path: &PathBuf) -> Option<String> {
+    venv_candidates(automation_path).into_iter().find(|p| p.exists()).map(|p| {
+        println!("   ✅ Found venv Python: {:?}", p);
+        p.to_string_lossy().to_string()
+    })
+}
+
+/// Strategies 4-5: fall back to whatever `python3`/`python` is on PATH,
+/// preferring one that already has the required packages importable.
+pub(crate) fn find_system_python() -> Option<String> {
     for cmd in ["python3", "python"] {
         if let Ok(output) = Command::new(cmd).arg("--version").output() {
             if output.status.success() {
-                // Check if required packages are available
                 let check = Command::new(cmd)
                     .args(["-c", "import loguru, playwright, openai"])
                     .output();
-                
+
                 if check.map(|o| o.status.success()).unwrap_or(false) {
                     println!("   ✅ System Python has required packages: {}", cmd);
-                return Some(cmd.to_string());
+                    return Some(cmd.to_string());
                 } else {
                     println!("   ⚠️  System Python found but missing packages: {}", cmd);
                 }
             }
         }
     }
-    
-    // 4. Return system Python anyway (will show proper error about missing packages)
+
     for cmd in ["python3", "python"] {
         if Command::new(cmd).arg("--version").output().map(|o| o.status.success()).unwrap_or(false) {
             println!("   Using system Python (packages may be missing): {}", cmd);
             return Some(cmd.to_string());
         }
     }
-    
+
     None
 }
 
+fn find_dev_python(app: &AppHandle, automation_path: &PathBuf) -> Option<String> {
+    println!("🐍 Looking for Python with packages...");
+
+    // 1-2. A venv already sitting next to the automation path or source project
+    if let Some(python) = find_dev_venv_python(automation_path) {
+        return Some(python);
+    }
+
+    // 3. No local venv: extract and provision the embedded standalone CPython
+    // distribution bundled as a resource, so end users need no system Python
+    if let Some(embedded_python) = provision_embedded_python(app, automation_path) {
+        println!("   ✅ Using embedded Python runtime: {}", embedded_python);
+        return Some(embedded_python);
+    }
+
+    // 4-5. Fall back to system Python (if packages are installed globally)
+    find_system_python()
+}
+
+// ==================== EMBEDDED PYTHON RUNTIME ====================
+
+/// Name of the marker file written after a successful extract+provision;
+/// its contents pin the archive/requirements state it was built from, so a
+/// later run only re-provisions when the bundled distribution or
+/// `requirements.txt` actually changes.
+const EMBEDDED_PYTHON_MARKER: &str = ".provisioned";
+
+/// Directory name (under `app_data_dir`) the embedded distribution is
+/// extracted and provisioned into.
+const EMBEDDED_PYTHON_DIR: &str = "python-runtime";
+
+fn embedded_python_archive_name() -> &'static str {
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return "cpython-aarch64-apple-darwin.tar.gz";
+
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    return "cpython-x86_64-apple-darwin.tar.gz";
+
+    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+    return "cpython-x86_64-pc-windows-msvc.tar.gz";
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    return "cpython-x86_64-unknown-linux-gnu.tar.gz";
+
+    #[cfg(not(any(
+        all(target_os = "macos", target_arch = "aarch64"),
+        all(target_os = "macos", target_arch = "x86_64"),
+        all(target_os = "windows", target_arch = "x86_64"),
+        all(target_os = "linux", target_arch = "x86_64")
+    )))]
+    return "cpython.tar.gz";
+}
+
+/// Locate the bundled standalone CPython archive (python-build-standalone
+/// style) shipped as a Tauri resource under `resources/python-dist/`.
+fn find_embedded_python_archive(app: &AppHandle) -> Option<PathBuf> {
+    let resource_dir = app.path_resolver().resource_dir()?;
+    let archive_path = resource_dir.join("python-dist").join(embedded_python_archive_name());
+    archive_path.exists().then_some(archive_path)
+}
+
+/// Extracts the embedded CPython distribution into `app_data_dir` and
+/// provisions a venv from it with `requirements.txt`, reusing the cached
+/// runtime unless the bundled archive or requirements file has changed
+/// since the marker was written.
+fn provision_embedded_python(app: &AppHandle, automation_path: &PathBuf) -> Option<String> {
+    let archive_path = find_embedded_python_archive(app)?;
+    let data_dir = app.path_resolver().app_data_dir()?;
+    let runtime_dir = data_dir.join(EMBEDDED_PYTHON_DIR);
+    let marker_path = runtime_dir.join(EMBEDDED_PYTHON_MARKER);
+    let requirements_path = automation_path.join("requirements.txt");
+
+    let marker_contents = format!(
+        "{:?}|{:?}",
+        std::fs::metadata(&archive_path).and_then(|m| m.modified()).ok(),
+        std::fs::metadata(&requirements_path).and_then(|m| m.modified()).ok(),
+    );
+
+    #[cfg(windows)]
+    let venv_python = runtime_dir.join("venv").join("Scripts").join("python.exe");
+    #[cfg(not(windows))]
+    let venv_python = runtime_dir.join("venv").join("bin").join("python");
+
+    if venv_python.exists() && std::fs::read_to_string(&marker_path).ok().as_deref() == Some(marker_contents.as_str()) {
+        log::info!("✅ Using cached embedded Python runtime: {:?}", venv_python);
+        return Some(venv_python.to_string_lossy().to_string());
+    }
+
+    log::info!("📦 Extracting embedded Python distribution to {:?}", runtime_dir);
+    std::fs::create_dir_all(&runtime_dir).ok()?;
+
+    let extracted = Command::new("tar")
+        .args(["-xzf", &archive_path.to_string_lossy(), "-C", &runtime_dir.to_string_lossy()])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if !extracted {
+        log::error!("❌ Failed to extract embedded Python distribution");
+        return None;
+    }
+
+    #[cfg(windows)]
+    let base_python = runtime_dir.join("python").join("python.exe");
+    #[cfg(not(windows))]
+    let base_python = runtime_dir.join("python").join("bin").join("python3");
+
+    if !base_python.exists() {
+        log::error!("❌ Extracted distribution is missing its interpreter at {:?}", base_python);
+        return None;
+    }
+
+    let venv_dir = runtime_dir.join("venv");
+    let venv_created = Command::new(&base_python)
+        .args(["-m", "venv", &venv_dir.to_string_lossy()])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if !venv_created {
+        log::error!("❌ Failed to create venv from embedded distribution");
+        return None;
+    }
+
+    let requirements_installed = Command::new(&venv_python)
+        .args(["-m", "pip", "install", "-r", &requirements_path.to_string_lossy()])
+        .current_dir(automation_path)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if !requirements_installed {
+        log::error!("❌ Failed to install requirements into embedded venv");
+        return None;
+    }
+
+    std::fs::write(&marker_path, &marker_contents).ok()?;
+    log::info!("✅ Embedded Python runtime provisioned at {:?}", venv_python);
+    Some(venv_python.to_string_lossy().to_string())
+}
+
+// ==================== DIAGNOSTICS ====================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticCheck {
+    pub name: String,
+    pub status: String,
+    pub detail: String,
+    #[serde(rename = "fixHint")]
+    pub fix_hint: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiagnosticReport {
+    pub checks: Vec<DiagnosticCheck>,
+}
+
+fn diag(name: &str, status: &str, detail: String, fix_hint: Option<&str>) -> DiagnosticCheck {
+    DiagnosticCheck {
+        name: name.to_string(),
+        status: status.to_string(),
+        detail,
+        fix_hint: fix_hint.map(|s| s.to_string()),
+    }
+}
+
+/// Enumerates every path `find_sidecar_binary` would check for the current platform,
+/// so diagnostics can report every candidate that exists rather than stopping
+/// at the first hit. The manual-search paths come first and the resource-dir
+/// paths last (the reverse of `resolve_sidecar`'s order), all built from
+/// `get_sidecar_name` rather than the `externalBin`-derived name.
+fn sidecar_candidates(app: &AppHandle) -> Vec<PathBuf> {
+    let sidecar_name = get_sidecar_name();
+    let mut candidates = Vec::new();
+
+    if let Ok(exe_path) = std::env::current_exe() {
+        #[cfg(target_os = "macos")]
+        {
+            if let Some(macos_dir) = exe_path.parent() {
+                if let Some(contents_dir) = macos_dir.parent() {
+                    candidates.push(contents_dir.join("Resources").join("_up_").join("automation").join(&sidecar_name));
+                    candidates.push(contents_dir.join("Resources").join(&sidecar_name));
+                }
+                candidates.push(macos_dir.join(&sidecar_name));
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            if let Some(exe_dir) = exe_path.parent() {
+                candidates.push(exe_dir.join("automation").join(&sidecar_name));
+                candidates.push(exe_dir.join("_up_").join("automation").join(&sidecar_name));
+                candidates.push(exe_dir.join("resources").join("automation").join(&sidecar_name));
+                candidates.push(exe_dir.join("resources").join("_up_").join("automation").join(&sidecar_name));
+                candidates.push(exe_dir.join(&sidecar_name));
+                candidates.push(exe_dir.join("inboxhunter-automation.exe"));
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(exe_dir) = exe_path.parent() {
+                candidates.push(exe_dir.join("automation").join(&sidecar_name));
+                candidates.push(exe_dir.join("_up_").join("automation").join(&sidecar_name));
+                candidates.push(exe_dir.join(&sidecar_name));
+                candidates.push(exe_dir.join("inboxhunter-automation"));
+            }
+        }
+    }
+
+    if let Some(resource_dir) = app.path_resolver().resource_dir() {
+        candidates.push(resource_dir.join("automation").join(&sidecar_name));
+        candidates.push(resource_dir.join(&sidecar_name));
+    }
+
+    candidates
+}
+
+/// Enumerates every venv path `find_dev_python` would check, in priority order,
+/// covering both the bundled/provided automation path and the source-tree
+/// fallback used when running from a build.
+fn venv_candidates(automation_path: &PathBuf) -> Vec<PathBuf> {
+    let mut candidates = vec![
+        automation_path.join("venv").join("bin").join("python"),
+        automation_path.join(".venv").join("bin").join("python"),
+        automation_path.join("venv").join("Scripts").join("python.exe"),
+        automation_path.join(".venv").join("Scripts").join("python.exe"),
+    ];
+
+    if let Ok(exe) = std::env::current_exe() {
+        let path_str = exe.to_string_lossy();
+        let target_pos = path_str.find("/target/").or_else(|| path_str.find("\\target\\"));
+        if let Some(target_pos) = target_pos {
+            let src_tauri_path = PathBuf::from(&path_str[..target_pos]);
+            if let Some(project_root) = src_tauri_path.parent() {
+                let source_automation = project_root.join("automation");
+                candidates.push(source_automation.join("venv").join("bin").join("python"));
+                candidates.push(source_automation.join(".venv").join("bin").join("python"));
+                candidates.push(source_automation.join("venv").join("Scripts").join("python.exe"));
+                candidates.push(source_automation.join(".venv").join("Scripts").join("python.exe"));
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Checks whether Playwright's Chromium is installed for the given interpreter
+/// by asking Playwright itself for the executable path it would launch.
+fn chromium_installed(python: &str) -> bool {
+    Command::new(python)
+        .args([
+            "-c",
+            "import os, sys; from playwright.sync_api import sync_playwright\n\
+             p = sync_playwright().start()\n\
+             ok = os.path.exists(p.chromium.executable_path)\n\
+             p.stop()\n\
+             sys.exit(0 if ok else 1)",
+        ])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Consolidates the probing logic scattered across `find_sidecar_binary`,
+/// `get_automation_path` and `find_dev_python` into a single report so users
+/// get a concrete, actionable answer instead of an opaque launch failure.
+#[command]
+pub async fn run_diagnostics(app: AppHandle) -> Result<DiagnosticReport, String> {
+    let mut checks = Vec::new();
+
+    let sidecar_hits: Vec<PathBuf> = sidecar_candidates(&app).into_iter().filter(|p| p.exists()).collect();
+    let selected_sidecar = resolve_sidecar(&app);
+    checks.push(match (&selected_sidecar, sidecar_hits.len()) {
+        (Some(resolution), 1) => diag(
+            "sidecar_binary",
+            "pass",
+            format!("Found sidecar at {} (via {})", resolution.path.display(), resolution.method),
+            None,
+        ),
+        (Some(resolution), n) => diag(
+            "sidecar_binary",
+            "warn",
+            format!(
+                "Found {} candidate sidecar binaries; start_bot would use {} (via {})",
+                n, resolution.path.display(), resolution.method
+            ),
+            Some("Remove stale sidecar binaries from other build locations to avoid ambiguity"),
+        ),
+        (None, _) => diag(
+            "sidecar_binary",
+            "fail",
+            "No bundled sidecar binary found".to_string(),
+            Some("Run a production build so the sidecar is bundled, or rely on a local Python environment instead"),
+        ),
+    });
+
+    let automation_path = get_automation_path();
+    checks.push(match &automation_path {
+        Some(path) => diag("automation_scripts", "pass", format!("Found automation/main.py at {}", path.display()), None),
+        None => diag(
+            "automation_scripts",
+            "fail",
+            "Could not find automation/main.py".to_string(),
+            Some("Ensure the automation folder ships alongside the app, or run from the project source tree"),
+        ),
+    });
+
+    if let Some(path) = &automation_path {
+        let venv_hits: Vec<PathBuf> = venv_candidates(path).into_iter().filter(|p| p.exists()).collect();
+        let selected_python = find_dev_python(&app, path);
+
+        checks.push(match (&selected_python, venv_hits.len()) {
+            (Some(python), 0) => diag(
+                "python_interpreter",
+                "warn",
+                format!("No virtual environment found; falling back to interpreter {} (embedded runtime or system Python)", python),
+                Some("Run setup_python_environment to create a dedicated venv"),
+            ),
+            (Some(python), 1) => diag("python_interpreter", "pass", format!("Using venv interpreter {}", python), None),
+            (Some(python), n) => diag(
+                "python_interpreter",
+                "warn",
+                format!("Found {} candidate virtual environments; start_bot would use {}", n, python),
+                Some("Remove the unused venv (bundled or source-tree) so the selection isn't ambiguous"),
+            ),
+            (None, _) => diag(
+                "python_interpreter",
+                "fail",
+                "No Python interpreter found".to_string(),
+                Some("Install Python 3.9+ and ensure it is on PATH"),
+            ),
+        });
+
+        if let Some(python) = &selected_python {
+            for pkg in ["loguru", "playwright", "openai"] {
+                let importable = Command::new(python)
+                    .args(["-c", &format!("import {}", pkg)])
+                    .output()
+                    .map(|o| o.status.success())
+                    .unwrap_or(false);
+
+                checks.push(if importable {
+                    diag(&format!("package_{}", pkg), "pass", format!("{} is importable", pkg), None)
+                } else {
+                    diag(
+                        &format!("package_{}", pkg),
+                        "fail",
+                        format!("{} is not importable", pkg),
+                        Some(&format!("pip install {} inside the automation virtual environment", pkg)),
+                    )
+                });
+            }
+
+            checks.push(if chromium_installed(python) {
+                diag("chromium", "pass", "Playwright's Chromium is installed".to_string(), None)
+            } else {
+                diag(
+                    "chromium",
+                    "fail",
+                    "Playwright's Chromium is not installed".to_string(),
+                    Some("Run: python -m playwright install chromium"),
+                )
+            });
+        }
+    }
+
+    Ok(DiagnosticReport { checks })
+}
+
+// ==================== PYTHON ENVIRONMENT BOOTSTRAP ====================
+
+#[derive(Debug)]
+enum PythonSetupError {
+    NoSystemPython,
+    VenvCreationFailed(String),
+    PipInstallFailed(String),
+    PlaywrightInstallFailed(String),
+}
+
+impl std::fmt::Display for PythonSetupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PythonSetupError::NoSystemPython => write!(f, "NO_SYSTEM_PYTHON: Python 3.9+ was not found. Please install it and try again."),
+            PythonSetupError::VenvCreationFailed(e) => write!(f, "VENV_CREATION_FAILED: {}", e),
+            PythonSetupError::PipInstallFailed(e) => write!(f, "PIP_INSTALL_FAILED: {}", e),
+            PythonSetupError::PlaywrightInstallFailed(e) => write!(f, "PLAYWRIGHT_INSTALL_FAILED: {}", e),
+        }
+    }
+}
+
+/// Runs a setup step (venv creation, pip install, playwright install),
+/// streaming its stdout/stderr line-by-line to the frontend the same way
+/// `spawn_log_reader` does for the bot process itself.
+fn run_setup_step(mut cmd: Command, app: &AppHandle, step: &str) -> Result<(), String> {
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+    let mut child = cmd.spawn().map_err(|e| format!("Failed to start {}: {}", step, e))?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    let stdout_handle = stdout.map(|out| {
+        let app = app.clone();
+        let step = step.to_string();
+        std::thread::spawn(move || {
+            for line in BufReader::new(out).lines().flatten() {
+                let _ = app.emit_all("python-setup-log", LogEvent { level: "info".to_string(), message: format!("[{}] {}", step, line), structured: None });
+            }
+        })
+    });
+    let stderr_handle = stderr.map(|err| {
+        let app = app.clone();
+        let step = step.to_string();
+        std::thread::spawn(move || {
+            for line in BufReader::new(err).lines().flatten() {
+                let _ = app.emit_all("python-setup-log", LogEvent { level: "warning".to_string(), message: format!("[{}] {}", step, line), structured: None });
+            }
+        })
+    });
+
+    let status = child.wait().map_err(|e| format!("Failed to wait for {}: {}", step, e))?;
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    if let Some(handle) = stderr_handle {
+        let _ = handle.join();
+    }
+
+    if !status.success() {
+        return Err(format!("{} exited with {:?}", step, status.code()));
+    }
+    Ok(())
+}
+
+/// Creates the `automation/venv` virtual environment, installs
+/// `requirements.txt`, and installs Playwright's Chromium - the three steps
+/// `find_dev_python` currently just hopes have already happened. If any venv
+/// already exists under the automation folder, returns without running any
+/// of the three steps. Progress is streamed to the frontend via
+/// `python-setup-log` events as each step runs.
+#[command]
+pub async fn setup_python_environment(app: AppHandle) -> Result<String, String> {
+    let automation_path = get_automation_path()
+        .ok_or_else(|| "Could not find automation folder".to_string())?;
+
+    let venv_paths = [
+        automation_path.join("venv").join("bin").join("python"),
+        automation_path.join(".venv").join("bin").join("python"),
+        automation_path.join("venv").join("Scripts").join("python.exe"),
+        automation_path.join(".venv").join("Scripts").join("python.exe"),
+    ];
+
+    if let Some(existing) = venv_paths.iter().find(|p| p.exists()) {
+        return Ok(format!("Virtual environment already set up at {}", existing.display()));
+    }
+
+    let system_python = ["python3", "python"]
+        .iter()
+        .find(|cmd| Command::new(cmd).arg("--version").output().map(|o| o.status.success()).unwrap_or(false))
+        .map(|s| s.to_string())
+        .ok_or_else(|| PythonSetupError::NoSystemPython.to_string())?;
+
+    let venv_dir = automation_path.join("venv");
+
+    let mut venv_cmd = Command::new(&system_python);
+    venv_cmd.args(["-m", "venv", &venv_dir.to_string_lossy()]);
+    run_setup_step(venv_cmd, &app, "venv")
+        .map_err(|e| PythonSetupError::VenvCreationFailed(e).to_string())?;
+
+    #[cfg(windows)]
+    let venv_python = venv_dir.join("Scripts").join("python.exe");
+    #[cfg(not(windows))]
+    let venv_python = venv_dir.join("bin").join("python");
+
+    let requirements = automation_path.join("requirements.txt");
+    let mut pip_cmd = Command::new(&venv_python);
+    pip_cmd
+        .args(["-m", "pip", "install", "-r", &requirements.to_string_lossy()])
+        .current_dir(&automation_path);
+    run_setup_step(pip_cmd, &app, "pip")
+        .map_err(|e| PythonSetupError::PipInstallFailed(e).to_string())?;
+
+    let mut playwright_cmd = Command::new(&venv_python);
+    playwright_cmd
+        .args(["-m", "playwright", "install", "chromium"])
+        .current_dir(&automation_path);
+    run_setup_step(playwright_cmd, &app, "playwright")
+        .map_err(|e| PythonSetupError::PlaywrightInstallFailed(e).to_string())?;
+
+    Ok("Python environment set up successfully".to_string())
+}
+
 #[command]
 pub async fn start_bot(
-    config: BotConfig,
+    mut config: BotConfig,
     state: State<'_, AppState>,
     app: AppHandle,
 ) -> Result<String, String> {
@@ -567,17 +1145,23 @@ pub async fn start_bot(
             return Err("Bot is already running".to_string());
         }
     }
-    
+
+    // Pull live API keys from the OS keychain rather than from settings.json,
+    // so they never land in inboxhunter.db or an exported CSV. Falls back to
+    // whatever came in on `config` (e.g. an empty string) if nothing is stored.
+    if let Some(openai_key) = get_secret_value(OPENAI_API_KEY_SECRET) {
+        config.api_keys.openai = openai_key;
+    }
+    if let Some(captcha_key) = get_secret_value(CAPTCHA_API_KEY_SECRET) {
+        config.api_keys.captcha = captcha_key;
+    }
+
     // Save config to temp file
     let data_dir = app.path_resolver()
         .app_data_dir()
         .ok_or("Failed to get app data directory")?;
     std::fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
 
-    // Clear any leftover stop signal file from previous run
-    let stop_signal_path = data_dir.join("stop_signal.txt");
-    let _ = std::fs::remove_file(&stop_signal_path);
-
     let config_path = data_dir.join("bot_config.json");
     let config_json = serde_json::to_string(&config).map_err(|e| e.to_string())?;
     std::fs::write(&config_path, &config_json).map_err(|e| e.to_string())?;
@@ -617,7 +1201,7 @@ pub async fn start_bot(
     // In dev mode: try Python first (for instant code updates)
     // In production: try sidecar first (self-contained executable)
     let sidecar_path = find_sidecar_binary(&app);
-    let automation_path = get_automation_path(&app);
+    let automation_path = get_automation_path();
 
     let use_python_first = is_dev_mode && automation_path.is_some();
     let use_sidecar_first = !is_dev_mode && sidecar_path.is_some();
@@ -627,7 +1211,7 @@ pub async fn start_bot(
         let automation_path = automation_path.unwrap();
         println!("🐍 Running with Python automation scripts (live code)");
 
-        let python_cmd = find_dev_python(&automation_path)
+        let python_cmd = find_dev_python(&app, &automation_path)
             .ok_or("Python not found. Please install Python 3.9+ and set up the virtual environment:\ncd automation && python3 -m venv venv && source venv/bin/activate && pip install -r requirements.txt && playwright install chromium")?;
 
         let main_script = automation_path.join("main.py");
@@ -643,6 +1227,7 @@ pub async fn start_bot(
         let mut cmd = Command::new(&python_cmd);
         cmd.args(&python_args)
             .current_dir(&automation_path)
+            .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             // Force UTF-8 encoding for Python stdout/stderr (fixes emoji on Windows)
@@ -692,6 +1277,7 @@ pub async fn start_bot(
 
         let mut cmd = Command::new(&sidecar_path);
         cmd.args(&args)
+            .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             // Force UTF-8 encoding for stdout/stderr (fixes emoji on Windows)
@@ -748,6 +1334,41 @@ pub async fn start_bot(
     Ok("Bot started successfully".to_string())
 }
 
+// ==================== CONTROL PROTOCOL ====================
+
+/// Commands sent to the child over stdin, one JSON object per line.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ControlMessage {
+    Stop,
+    Pause,
+    Resume,
+    Status,
+    SetConcurrency { n: u32 },
+}
+
+/// Replies the child sends back on stdout, one JSON object per line.
+/// Any stdout line that doesn't parse as one of these is treated as a plain
+/// log line instead.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ControlReply {
+    Ack { cmd: String },
+    Progress { done: u64, total: u64 },
+}
+
+/// Serializes a `ControlMessage` as a single NDJSON line and writes it to
+/// the child's stdin.
+fn send_control_message(child: &mut Child, message: &ControlMessage) -> std::io::Result<()> {
+    use std::io::Write;
+    let stdin = child.stdin.as_mut().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::BrokenPipe, "child has no stdin pipe")
+    })?;
+    let line = serde_json::to_string(message)?;
+    writeln!(stdin, "{}", line)?;
+    stdin.flush()
+}
+
 fn spawn_log_reader(
     stdout: Option<std::process::ChildStdout>,
     stderr: Option<std::process::ChildStderr>,
@@ -759,14 +1380,28 @@ fn spawn_log_reader(
         std::thread::spawn(move || {
             let reader = BufReader::new(stdout);
             for line in reader.lines().flatten() {
-                let level = determine_log_level(&line);
-                    let _ = app_handle.emit_all("bot-log", LogEvent {
-                        level: level.to_string(),
-                        message: line,
-                    });
-            }
-            
-            // Process ended - clean up
+                match serde_json::from_str::<ControlReply>(&line) {
+                    Ok(ControlReply::Ack { cmd }) => {
+                        if cmd == "stop" {
+                            let state: State<'_, AppState> = app_handle.state();
+                            state.stop_acked.store(true, std::sync::atomic::Ordering::SeqCst);
+                        }
+                        let _ = app_handle.emit_all("bot-ack", cmd);
+                    }
+                    Ok(ControlReply::Progress { done, total }) => {
+                        let _ = app_handle.emit_all("bot-progress", (done, total));
+                    }
+                    Err(_) => {
+                        let event = parse_log_line(&line);
+                        let state: State<'_, AppState> = app_handle.state();
+                        state.log_counters.record(&event.level);
+                        push_log_history(&app_handle, event.clone());
+                        let _ = app_handle.emit_all("bot-log", event);
+                    }
+                }
+            }
+
+            // Process ended - clean up
             let state: State<'_, AppState> = app_handle.state();
             if let Ok(mut running) = state.bot_running.lock() {
                 *running = false;
@@ -787,16 +1422,59 @@ fn spawn_log_reader(
         std::thread::spawn(move || {
             let reader = BufReader::new(stderr);
             for line in reader.lines().flatten() {
-                    let _ = app_handle2.emit_all("bot-log", LogEvent {
-                        level: "error".to_string(),
-                        message: line,
-                    });
+                let event = LogEvent {
+                    level: "error".to_string(),
+                    message: line,
+                    structured: None,
+                };
+                push_log_history(&app_handle2, event.clone());
+                let _ = app_handle2.emit_all("bot-log", event);
             }
         });
     }
 }
 
+/// Appends a log event to the ring buffer backing the "copy diagnostics" button,
+/// dropping the oldest entry once `LOG_HISTORY_CAPACITY` is reached.
+fn push_log_history(app: &AppHandle, event: LogEvent) {
+    let state: State<'_, AppState> = app.state();
+    if let Ok(mut history) = state.log_history.lock() {
+        if history.len() >= LOG_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(event);
+    }
+}
+
+/// Maps loguru's uppercase level tokens to the lowercase names the rest of
+/// the app matches on (`LogCounters::record`, the frontend's log filters).
+fn normalize_loguru_level(level: &str) -> Option<&'static str> {
+    match level.trim() {
+        "ERROR" | "CRITICAL" => Some("error"),
+        "WARNING" => Some("warning"),
+        "SUCCESS" => Some("success"),
+        "DEBUG" | "TRACE" => Some("debug"),
+        "INFO" => Some("info"),
+        _ => None,
+    }
+}
+
+/// Parses loguru's default line format (`TIMESTAMP | LEVEL | module:function:line - message`)
+/// and returns the normalized level, if the line matches.
+fn parse_loguru_level(line: &str) -> Option<&'static str> {
+    let mut parts = line.splitn(3, " | ");
+    let _timestamp = parts.next()?;
+    let level = parts.next()?;
+    parts.next()?;
+
+    normalize_loguru_level(level)
+}
+
 fn determine_log_level(line: &str) -> &'static str {
+    if let Some(level) = parse_loguru_level(line) {
+        return level;
+    }
+
     if line.contains("ERROR") || line.contains("❌") {
         "error"
     } else if line.contains("WARNING") || line.contains("⚠️") {
@@ -810,17 +1488,41 @@ fn determine_log_level(line: &str) -> &'static str {
     }
 }
 
+/// Parses one sidecar stdout line into a `LogEvent`, preferring a structured
+/// NDJSON line (carrying module/timestamp/fields) over the plaintext
+/// heuristic so the UI can group and filter reliably when the sidecar opts in.
+fn parse_log_line(line: &str) -> LogEvent {
+    if let Ok(structured) = serde_json::from_str::<StructuredLog>(line) {
+        let level = normalize_loguru_level(&structured.level)
+            .map(|level| level.to_string())
+            .unwrap_or_else(|| structured.level.clone());
+        return LogEvent {
+            level,
+            message: structured.message.clone(),
+            structured: serde_json::to_value(&structured).ok(),
+        };
+    }
+
+    LogEvent {
+        level: determine_log_level(line).to_string(),
+        message: line.to_string(),
+        structured: None,
+    }
+}
+
 #[command]
-pub async fn stop_bot(state: State<'_, AppState>, app: AppHandle) -> Result<String, String> {
-    // Get app data directory for stop signal file
-    let data_dir = app.path_resolver()
-        .app_data_dir()
-        .ok_or("Failed to get app data directory")?;
-    let stop_signal_path = data_dir.join("stop_signal.txt");
+pub async fn stop_bot(state: State<'_, AppState>, _app: AppHandle) -> Result<String, String> {
+    state.stop_acked.store(false, std::sync::atomic::Ordering::SeqCst);
 
-    // Create stop signal file - Python will check for this and stop gracefully
-    std::fs::write(&stop_signal_path, "stop").map_err(|e| e.to_string())?;
-    println!("📝 Created stop signal file: {}", stop_signal_path.display());
+    // Ask the child to stop gracefully over the control channel
+    {
+        let mut process = state.bot_process.lock().map_err(|e| e.to_string())?;
+        if let Some(ref mut child) = *process {
+            if let Err(e) = send_control_message(child, &ControlMessage::Stop) {
+                log::warn!("⚠️ Failed to send stop control message: {}", e);
+            }
+        }
+    }
 
     // Wait for process to exit gracefully
     {
@@ -844,7 +1546,11 @@ pub async fn stop_bot(state: State<'_, AppState>, app: AppHandle) -> Result<Stri
                     Ok(None) => {
                         // Still running
                         if waited >= max_wait_ms {
-                            println!("⚠️ Bot didn't stop gracefully, forcing termination...");
+                            let acked = state.stop_acked.load(std::sync::atomic::Ordering::SeqCst);
+                            println!(
+                                "⚠️ Bot didn't stop gracefully (stop ack {}), forcing termination...",
+                                if acked { "received" } else { "never arrived" }
+                            );
                             // Force kill
                             #[cfg(unix)]
                             {
@@ -880,9 +1586,6 @@ pub async fn stop_bot(state: State<'_, AppState>, app: AppHandle) -> Result<Stri
         *process = None;
     }
 
-    // Clean up stop signal file
-    let _ = std::fs::remove_file(&stop_signal_path);
-
     // Mark as not running
     {
         let mut running = state.bot_running.lock().map_err(|e| e.to_string())?;
@@ -898,6 +1601,182 @@ pub async fn get_bot_status(state: State<'_, AppState>) -> Result<bool, String>
     Ok(*running)
 }
 
+/// Sends a single `ControlMessage` to the running bot over its control
+/// channel, erroring out if there's no child to send it to.
+fn send_bot_control_message(state: &State<'_, AppState>, message: &ControlMessage) -> Result<(), String> {
+    let mut process = state.bot_process.lock().map_err(|e| e.to_string())?;
+    let child = process.as_mut().ok_or("Bot is not running")?;
+    send_control_message(child, message).map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn pause_bot(state: State<'_, AppState>) -> Result<String, String> {
+    send_bot_control_message(&state, &ControlMessage::Pause)?;
+    Ok("Pause requested".to_string())
+}
+
+#[command]
+pub async fn resume_bot(state: State<'_, AppState>) -> Result<String, String> {
+    send_bot_control_message(&state, &ControlMessage::Resume)?;
+    Ok("Resume requested".to_string())
+}
+
+#[command]
+pub async fn request_bot_status(state: State<'_, AppState>) -> Result<String, String> {
+    send_bot_control_message(&state, &ControlMessage::Status)?;
+    Ok("Status requested".to_string())
+}
+
+#[command]
+pub async fn set_bot_concurrency(state: State<'_, AppState>, n: u32) -> Result<String, String> {
+    send_bot_control_message(&state, &ControlMessage::SetConcurrency { n })?;
+    Ok(format!("Concurrency set to {}", n))
+}
+
+// ==================== WORKLOAD RUNNER ====================
+
+/// A reproducible scraping/benchmark workload: an exact URL set plus the
+/// bot config to run it with, so config or model changes can be compared
+/// against a stored baseline of the same `name` instead of eyeballed from logs.
+#[derive(Debug, Deserialize)]
+pub struct WorkloadFile {
+    pub name: String,
+    pub urls: Vec<String>,
+    pub settings: BotConfig,
+    #[serde(rename = "expectedMinSuccessRate", default)]
+    pub expected_min_success_rate: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkloadResult {
+    pub name: String,
+    #[serde(rename = "durationMs")]
+    pub duration_ms: i64,
+    #[serde(rename = "urlsTotal")]
+    pub urls_total: i32,
+    #[serde(rename = "urlsSuccess")]
+    pub urls_success: i32,
+    #[serde(rename = "successRate")]
+    pub success_rate: f64,
+    #[serde(rename = "totalCost")]
+    pub total_cost: f64,
+    #[serde(rename = "totalTokens")]
+    pub total_tokens: i64,
+    #[serde(rename = "urlsPerSec")]
+    pub urls_per_sec: f64,
+    pub regression: bool,
+    #[serde(rename = "regressionDetail")]
+    pub regression_detail: Option<String>,
+}
+
+/// Runs the bot against exactly the URL set described by the workload file
+/// at `path`, then records a structured result row so repeat runs of the
+/// same `name` are comparable and regressions against the stored baseline
+/// are flagged automatically instead of eyeballed from logs.
+#[command]
+pub async fn run_workload(
+    path: String,
+    state: State<'_, AppState>,
+    app: AppHandle,
+) -> Result<WorkloadResult, String> {
+    let workload_json = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read workload file: {}", e))?;
+    let workload: WorkloadFile = serde_json::from_str(&workload_json).map_err(|e| format!("Invalid workload file: {}", e))?;
+
+    let data_dir = app.path_resolver().app_data_dir().ok_or("Failed to get app data directory")?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+
+    let workload_csv_path = data_dir.join(format!("workload_{}.csv", workload.name.replace(' ', "_")));
+    let mut csv_contents = String::from("url\n");
+    for url in &workload.urls {
+        csv_contents.push_str(url);
+        csv_contents.push('\n');
+    }
+    std::fs::write(&workload_csv_path, csv_contents).map_err(|e| e.to_string())?;
+
+    let mut config = workload.settings;
+    config.settings.data_source = "csv".to_string();
+    config.settings.csv_path = workload_csv_path.to_string_lossy().to_string();
+
+    let stats_before = db::get_processed_stats(&state.db_pool).map_err(|e| e.to_string())?;
+    let cost_before = db::get_api_cost_summary(&state.db_pool).map_err(|e| e.to_string())?;
+
+    let start = std::time::Instant::now();
+    start_bot(config, state.clone(), app.clone()).await?;
+
+    loop {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        let still_running = *state.bot_running.lock().map_err(|e| e.to_string())?;
+        if !still_running {
+            break;
+        }
+    }
+    let duration = start.elapsed();
+
+    let stats_after = db::get_processed_stats(&state.db_pool).map_err(|e| e.to_string())?;
+    let cost_after = db::get_api_cost_summary(&state.db_pool).map_err(|e| e.to_string())?;
+
+    let urls_total = workload.urls.len() as i32;
+    let urls_success = (stats_after.successful - stats_before.successful).max(0);
+    let success_rate = if urls_total > 0 { urls_success as f64 / urls_total as f64 } else { 0.0 };
+    let total_cost = cost_after.total_cost - cost_before.total_cost;
+    let total_tokens = cost_after.total_tokens - cost_before.total_tokens;
+    let urls_per_sec = if duration.as_secs_f64() > 0.0 { urls_total as f64 / duration.as_secs_f64() } else { 0.0 };
+
+    let baseline = db::get_workload_baseline(&state.db_pool, &workload.name).map_err(|e| e.to_string())?;
+
+    let min_success_rate = workload.expected_min_success_rate.unwrap_or(0.0);
+    let mut regression = success_rate < min_success_rate;
+    let mut regression_detail = regression.then(|| format!(
+        "success rate {:.1}% is below the expected minimum {:.1}%",
+        success_rate * 100.0,
+        min_success_rate * 100.0
+    ));
+
+    if let Some(baseline) = &baseline {
+        if success_rate + 0.05 < baseline.success_rate {
+            regression = true;
+            regression_detail = Some(format!(
+                "success rate {:.1}% regressed from baseline {:.1}%",
+                success_rate * 100.0,
+                baseline.success_rate * 100.0
+            ));
+        } else if baseline.total_cost > 0.0 && total_cost > baseline.total_cost * 1.2 {
+            regression = true;
+            regression_detail = Some(format!(
+                "cost ${:.2} is {:.0}% higher than baseline ${:.2}",
+                total_cost,
+                (total_cost / baseline.total_cost - 1.0) * 100.0,
+                baseline.total_cost
+            ));
+        }
+    }
+
+    db::record_workload_result(
+        &state.db_pool,
+        &workload.name,
+        duration.as_millis() as i64,
+        urls_total,
+        urls_success,
+        success_rate,
+        total_cost,
+        total_tokens,
+        urls_per_sec,
+    ).map_err(|e| e.to_string())?;
+
+    Ok(WorkloadResult {
+        name: workload.name,
+        duration_ms: duration.as_millis() as i64,
+        urls_total,
+        urls_success,
+        success_rate,
+        total_cost,
+        total_tokens,
+        urls_per_sec,
+        regression,
+        regression_detail,
+    })
+}
+
 // ==================== PROCESSED URLs COMMANDS ====================
 
 #[command]
@@ -905,39 +1784,44 @@ pub async fn get_processed_urls(
     state: State<'_, AppState>,
     limit: Option<i32>,
 ) -> Result<Vec<ProcessedURL>, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let urls = db::get_processed_urls(&db_path, limit.unwrap_or(100)).map_err(|e| e.to_string())?;
+    let urls = db::get_processed_urls(&state.db_pool, limit.unwrap_or(100)).map_err(|e| e.to_string())?;
     Ok(urls)
 }
 
 #[command]
 pub async fn get_processed_stats(state: State<'_, AppState>) -> Result<ProcessedStats, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let stats = db::get_processed_stats(&db_path).map_err(|e| e.to_string())?;
+    let stats = db::get_processed_stats(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(stats)
 }
 
 #[command]
 pub async fn delete_processed_url(state: State<'_, AppState>, id: i32) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::delete_processed_url(&db_path, id).map_err(|e| e.to_string())?;
+    db::delete_processed_url(&state.db_pool, id).map_err(|e| e.to_string())?;
     Ok("Record deleted".to_string())
 }
 
 #[command]
 pub async fn clear_processed_urls(state: State<'_, AppState>) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::clear_processed_urls(&db_path).map_err(|e| e.to_string())?;
+    db::clear_processed_urls(&state.db_pool).map_err(|e| e.to_string())?;
     Ok("All processed URLs cleared".to_string())
 }
 
 #[command]
 pub async fn export_processed_csv(state: State<'_, AppState>) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let csv = db::export_processed_csv(&db_path).map_err(|e| e.to_string())?;
+    let csv = db::export_processed_csv(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(csv)
 }
 
+#[command]
+pub async fn search_processed(
+    state: State<'_, AppState>,
+    query: String,
+    limit: Option<i32>,
+) -> Result<Vec<ProcessedURL>, String> {
+    let results = db::search_processed(&state.db_pool, &query, limit.unwrap_or(100)).map_err(|e| e.to_string())?;
+    Ok(results)
+}
+
 // ==================== SCRAPED URLs COMMANDS ====================
 
 #[command]
@@ -945,22 +1829,19 @@ pub async fn get_scraped_urls(
     state: State<'_, AppState>,
     limit: Option<i32>,
 ) -> Result<Vec<ScrapedURL>, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let urls = db::get_scraped_urls(&db_path, limit.unwrap_or(100)).map_err(|e| e.to_string())?;
+    let urls = db::get_scraped_urls(&state.db_pool, limit.unwrap_or(100)).map_err(|e| e.to_string())?;
     Ok(urls)
 }
 
 #[command]
 pub async fn get_scraped_stats(state: State<'_, AppState>) -> Result<ScrapedStats, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let stats = db::get_scraped_stats(&db_path).map_err(|e| e.to_string())?;
+    let stats = db::get_scraped_stats(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(stats)
 }
 
 #[command]
 pub async fn delete_scraped_url(state: State<'_, AppState>, id: i32) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::delete_scraped_url(&db_path, id).map_err(|e| e.to_string())?;
+    db::delete_scraped_url(&state.db_pool, id).map_err(|e| e.to_string())?;
     Ok("Record deleted".to_string())
 }
 
@@ -970,46 +1851,290 @@ pub async fn update_scraped_url_status(
     id: i32,
     processed: bool
 ) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::update_scraped_url_status(&db_path, id, processed).map_err(|e| e.to_string())?;
+    db::update_scraped_url_status(&state.db_pool, id, processed).map_err(|e| e.to_string())?;
     Ok(format!("Status updated to {}", if processed { "Done" } else { "Pending" }))
 }
 
 #[command]
 pub async fn clear_scraped_urls(state: State<'_, AppState>) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::clear_scraped_urls(&db_path).map_err(|e| e.to_string())?;
+    db::clear_scraped_urls(&state.db_pool).map_err(|e| e.to_string())?;
     Ok("All scraped URLs cleared".to_string())
 }
 
 #[command]
 pub async fn export_scraped_csv(state: State<'_, AppState>) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let csv = db::export_scraped_csv(&db_path).map_err(|e| e.to_string())?;
+    let csv = db::export_scraped_csv(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(csv)
 }
 
+#[command]
+pub async fn search_scraped(
+    state: State<'_, AppState>,
+    query: String,
+    limit: Option<i32>,
+) -> Result<Vec<ScrapedURL>, String> {
+    let results = db::search_scraped(&state.db_pool, &query, limit.unwrap_or(100)).map_err(|e| e.to_string())?;
+    Ok(results)
+}
+
+// Cap on how many un-enriched rows `enrich_all_pending` fetches per call, so
+// a multi-thousand-row scrape queue can't turn one command into an unbounded
+// crawl - the frontend can just call it again for the next batch.
+const ENRICH_BATCH_LIMIT: i32 = 200;
+
+fn html_unescape(text: &str) -> String {
+    text.replace("&amp;", "&")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+}
+
+/// Pulls `content="..."` out of a `<meta property="{key}" ...>` or
+/// `<meta name="{key}" ...>` tag, tolerating either attribute order since
+/// pages are inconsistent about which comes first.
+fn extract_meta_content(html: &str, key: &str) -> Option<String> {
+    use regex::Regex;
+
+    let escaped_key = regex::escape(key);
+    let patterns = [
+        format!(r#"(?is)<meta[^>]+(?:property|name)=["']{}["'][^>]*content=["']([^"']*)["']"#, escaped_key),
+        format!(r#"(?is)<meta[^>]+content=["']([^"']*)["'][^>]*(?:property|name)=["']{}["']"#, escaped_key),
+    ];
+
+    patterns.iter().find_map(|pattern| {
+        Regex::new(pattern).ok()?.captures(html)?.get(1).map(|m| html_unescape(m.as_str()))
+    })
+}
+
+/// Falls back to the text content of the first `<title>` or `<h1>` when a
+/// page has no OpenGraph/Twitter-card tags at all.
+fn extract_tag_text(html: &str, tag: &str) -> Option<String> {
+    use regex::Regex;
+
+    let pattern = format!(r"(?is)<{tag}[^>]*>(.*?)</{tag}>", tag = regex::escape(tag));
+    let text = Regex::new(&pattern).ok()?.captures(html)?.get(1)?.as_str().trim().to_string();
+    if text.is_empty() { None } else { Some(html_unescape(&text)) }
+}
+
+/// Fetches `url` and parses its OpenGraph/Twitter-card metadata, falling
+/// back to `<title>` then the first `<h1>` for the title when neither tag
+/// family is present.
+async fn fetch_link_metadata(client: &reqwest::Client, url: &str) -> Result<LinkMetadata, String> {
+    let response = client
+        .get(url)
+        .header("User-Agent", "InboxHunter-App")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch {} ({})", url, response.status()));
+    }
+
+    let html = response.text().await.map_err(|e| format!("Failed to read response body: {}", e))?;
+
+    let title = extract_meta_content(&html, "og:title")
+        .or_else(|| extract_meta_content(&html, "twitter:title"))
+        .or_else(|| extract_tag_text(&html, "title"))
+        .or_else(|| extract_tag_text(&html, "h1"));
+    let description = extract_meta_content(&html, "og:description")
+        .or_else(|| extract_meta_content(&html, "twitter:description"));
+    let site_name = extract_meta_content(&html, "og:site_name");
+    let image = extract_meta_content(&html, "og:image")
+        .or_else(|| extract_meta_content(&html, "twitter:image"));
+
+    Ok(LinkMetadata { title, description, site_name, image })
+}
+
+#[command]
+pub async fn enrich_scraped_url(state: State<'_, AppState>, id: i32, url: String) -> Result<LinkMetadata, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let metadata = fetch_link_metadata(&client, &url).await?;
+    let metadata_json = serde_json::to_string(&metadata).map_err(|e| e.to_string())?;
+    db::set_scraped_url_metadata(&state.db_pool, id, &metadata_json).map_err(|e| e.to_string())?;
+    Ok(metadata)
+}
+
+#[command]
+pub async fn enrich_all_pending(state: State<'_, AppState>) -> Result<usize, String> {
+    let unenriched = db::get_scraped_urls_missing_metadata(&state.db_pool, ENRICH_BATCH_LIMIT).map_err(|e| e.to_string())?;
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut enriched = 0;
+    for scraped in unenriched {
+        let metadata = match fetch_link_metadata(&client, &scraped.url).await {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                let _ = db::record_enrich_failure(&state.db_pool, scraped.id);
+                continue;
+            }
+        };
+        let metadata_json = match serde_json::to_string(&metadata) {
+            Ok(json) => json,
+            Err(_) => {
+                let _ = db::record_enrich_failure(&state.db_pool, scraped.id);
+                continue;
+            }
+        };
+        if db::set_scraped_url_metadata(&state.db_pool, scraped.id, &metadata_json).is_ok() {
+            enriched += 1;
+        } else {
+            let _ = db::record_enrich_failure(&state.db_pool, scraped.id);
+        }
+    }
+
+    Ok(enriched)
+}
+
 #[command]
 pub async fn retry_failed_urls(state: State<'_, AppState>) -> Result<usize, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let count = db::retry_failed_urls(&db_path).map_err(|e| e.to_string())?;
+    let count = db::retry_failed_urls(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(count)
 }
 
 #[command]
 pub async fn retry_url_by_id(state: State<'_, AppState>, id: i32) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::retry_url_by_id(&db_path, id).map_err(|e| e.to_string())?;
+    db::retry_url_by_id(&state.db_pool, id).map_err(|e| e.to_string())?;
     Ok("URL reset for retry".to_string())
 }
 
 #[command]
 pub async fn get_failed_count(state: State<'_, AppState>) -> Result<i32, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let count = db::get_failed_count(&db_path).map_err(|e| e.to_string())?;
+    let count = db::get_failed_count(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(count)
 }
 
+// ==================== METRICS ====================
+
+#[command]
+pub async fn get_prometheus_metrics(state: State<'_, AppState>) -> Result<String, String> {
+    let bot_running = *state.bot_running.lock().map_err(|e| e.to_string())?;
+    crate::metrics::render_metrics(&state.db_pool, bot_running, &state.log_counters).map_err(|e| e.to_string())
+}
+
+// ==================== INGESTION QUOTA COMMANDS ====================
+
+#[command]
+pub async fn set_quota(
+    state: State<'_, AppState>,
+    scope: String,
+    value: String,
+    max: i64,
+) -> Result<String, String> {
+    db::set_quota(&state.db_pool, &scope, &value, max).map_err(|e| e.to_string())?;
+    Ok("Quota set".to_string())
+}
+
+#[command]
+pub async fn get_quota_usage(state: State<'_, AppState>) -> Result<Vec<(String, String, i64, i64)>, String> {
+    let usage = db::get_quota_usage(&state.db_pool).map_err(|e| e.to_string())?;
+    Ok(usage)
+}
+
+#[command]
+pub async fn recount_quotas(state: State<'_, AppState>) -> Result<String, String> {
+    db::recount_quotas(&state.db_pool).map_err(|e| e.to_string())?;
+    Ok("Quota counters recomputed".to_string())
+}
+
+// ==================== SECRET STORAGE COMMANDS ====================
+
+/// Service name under which all InboxHunter secrets are filed in the OS
+/// keychain, so entries don't collide with unrelated apps using the same
+/// keyring backend.
+const SECRET_SERVICE: &str = "InboxHunter-App";
+
+/// Keychain entry names for `BotConfig::api_keys`, shared by `save_settings`
+/// (writes here instead of `settings.json`), `load_settings` (reads back),
+/// and `start_bot` (reads at launch time).
+const OPENAI_API_KEY_SECRET: &str = "openai_api_key";
+const CAPTCHA_API_KEY_SECRET: &str = "captcha_api_key";
+
+/// Reads a secret without surfacing "not found" as an error - callers that
+/// just want to fall back to an empty/default value use this instead of
+/// `get_secret`.
+fn get_secret_value(key: &str) -> Option<String> {
+    keyring::Entry::new(SECRET_SERVICE, key).ok()?.get_password().ok()
+}
+
+/// Removes a keychain entry, treating "wasn't there" as success - callers
+/// clearing a field to empty just want the stale secret gone either way.
+fn delete_secret_value(key: &str) -> Result<(), String> {
+    let entry = keyring::Entry::new(SECRET_SERVICE, key).map_err(|e| e.to_string())?;
+    match entry.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[command]
+pub async fn set_secret(key: String, value: String) -> Result<(), String> {
+    let entry = keyring::Entry::new(SECRET_SERVICE, &key).map_err(|e| e.to_string())?;
+    entry.set_password(&value).map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn get_secret(key: String) -> Result<Option<String>, String> {
+    let entry = keyring::Entry::new(SECRET_SERVICE, &key).map_err(|e| e.to_string())?;
+    match entry.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// ==================== SYSTEM SHELL COMMANDS ====================
+
+/// Opens `url` in the user's default browser.
+#[command]
+pub async fn open_external(url: String) -> Result<(), String> {
+    open::that(url).map_err(|e| e.to_string())
+}
+
+/// Opens the folder containing `path` (e.g. a freshly exported CSV) in the
+/// system's default file manager.
+#[command]
+pub async fn reveal_export(path: String) -> Result<(), String> {
+    let folder = std::path::Path::new(&path)
+        .parent()
+        .ok_or("Path has no parent folder")?;
+    open::that(folder).map_err(|e| e.to_string())
+}
+
+// ==================== SEARCH STATE COMMANDS ====================
+
+#[command]
+pub async fn store_search_string(
+    state: State<'_, AppState>,
+    page: String,
+    string: String,
+) -> Result<(), String> {
+    let mut search_strings = state.search_strings.lock().map_err(|e| e.to_string())?;
+    search_strings.insert(page, string);
+    Ok(())
+}
+
+/// Returns the saved search string for `page`, if any, and clears it - a
+/// one-shot restore so a filter from a previous visit doesn't silently
+/// keep reapplying itself on every future navigation to the same page.
+#[command]
+pub async fn get_stored_search_string(
+    state: State<'_, AppState>,
+    page: String,
+) -> Result<Option<String>, String> {
+    let mut search_strings = state.search_strings.lock().map_err(|e| e.to_string())?;
+    Ok(search_strings.remove(&page))
+}
+
 // ==================== API COST TRACKING COMMANDS ====================
 
 #[command]
@@ -1017,39 +2142,51 @@ pub async fn get_api_sessions(
     state: State<'_, AppState>,
     limit: Option<i32>,
 ) -> Result<Vec<ApiSession>, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let sessions = db::get_api_sessions(&db_path, limit.unwrap_or(50)).map_err(|e| e.to_string())?;
+    let sessions = db::get_api_sessions(&state.db_pool, limit.unwrap_or(50)).map_err(|e| e.to_string())?;
     Ok(sessions)
 }
 
 #[command]
 pub async fn get_api_cost_summary(state: State<'_, AppState>) -> Result<ApiCostSummary, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    let summary = db::get_api_cost_summary(&db_path).map_err(|e| e.to_string())?;
+    let summary = db::get_api_cost_summary(&state.db_pool).map_err(|e| e.to_string())?;
     Ok(summary)
 }
 
 #[command]
 pub async fn clear_api_sessions(state: State<'_, AppState>) -> Result<String, String> {
-    let db_path = state.db_path.lock().map_err(|e| e.to_string())?;
-    db::clear_api_sessions(&db_path).map_err(|e| e.to_string())?;
+    db::clear_api_sessions(&state.db_pool).map_err(|e| e.to_string())?;
     Ok("API session history cleared".to_string())
 }
 
 #[command]
 pub async fn save_settings(
-    config: BotConfig,
+    mut config: BotConfig,
     app: AppHandle,
 ) -> Result<String, String> {
     let data_dir = app.path_resolver()
         .app_data_dir()
         .ok_or("Failed to get app data directory")?;
-    
+
+    // API keys go to the OS keychain, never to plaintext settings.json. An
+    // empty field means the user cleared the key in the UI, so the stale
+    // keychain entry must be removed rather than just left untouched.
+    if !config.api_keys.openai.is_empty() {
+        set_secret(OPENAI_API_KEY_SECRET.to_string(), config.api_keys.openai.clone()).await?;
+    } else {
+        delete_secret_value(OPENAI_API_KEY_SECRET)?;
+    }
+    if !config.api_keys.captcha.is_empty() {
+        set_secret(CAPTCHA_API_KEY_SECRET.to_string(), config.api_keys.captcha.clone()).await?;
+    } else {
+        delete_secret_value(CAPTCHA_API_KEY_SECRET)?;
+    }
+    config.api_keys = APIKeys { openai: String::new(), captcha: String::new() };
+
     let settings_path = data_dir.join("settings.json");
     let config_json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
-    
+
     std::fs::write(&settings_path, config_json).map_err(|e| e.to_string())?;
-    
+
     Ok("Settings saved".to_string())
 }
 
@@ -1066,17 +2203,155 @@ pub async fn load_settings(app: AppHandle) -> Result<Option<BotConfig>, String>
     }
     
     let config_json = std::fs::read_to_string(&settings_path).map_err(|e| e.to_string())?;
-    let config: BotConfig = serde_json::from_str(&config_json).map_err(|e| e.to_string())?;
-    
+    let mut config: BotConfig = serde_json::from_str(&config_json).map_err(|e| e.to_string())?;
+
+    // api_keys aren't in settings.json - source them from the keychain instead.
+    config.api_keys.openai = get_secret_value(OPENAI_API_KEY_SECRET).unwrap_or_default();
+    config.api_keys.captcha = get_secret_value(CAPTCHA_API_KEY_SECRET).unwrap_or_default();
+
     Ok(Some(config))
 }
 
+// ==================== AUTO-UPDATE COMMANDS ====================
+
+/// Base64-encoded ed25519 public key the release pipeline signs update
+/// bundles with. Placeholder - replace with the real signing key before
+/// shipping a build with the updater enabled.
+const UPDATE_PUBLIC_KEY: &str = "REPLACE_WITH_RELEASE_SIGNING_PUBLIC_KEY_BASE64";
+
+/// What the configured `releaseEndpoint` is expected to return.
+#[derive(Debug, Deserialize)]
+struct UpdateManifest {
+    version: String,
+    #[serde(rename = "downloadUrl")]
+    download_url: String,
+    /// Base64-encoded ed25519 signature of the bundle at `download_url`.
+    signature: String,
+}
+
+/// What `check_for_update` hands back to the frontend and emits as an event payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    pub version: String,
+    #[serde(rename = "downloadUrl")]
+    pub download_url: String,
+    pub signature: String,
+}
+
+/// Queries the configured release endpoint for the newest build on the
+/// configured channel, stamps `lastChecked`, and emits `update-available` if
+/// it's newer than the running version. Returns `Ok(None)` - rather than an
+/// error - when the updater hasn't been configured yet, so a stock install
+/// doesn't fail its startup check.
+#[command]
+pub async fn check_for_update(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    let config = load_settings(app.clone()).await?;
+    let mut update_settings = match config.as_ref().and_then(|c| c.update_settings.clone()) {
+        Some(update_settings) => update_settings,
+        None => return Ok(None),
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let manifest: UpdateManifest = client
+        .get(&update_settings.release_endpoint)
+        .query(&[("channel", update_settings.channel.as_str())])
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach release endpoint: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Malformed update manifest: {}", e))?;
+
+    update_settings.last_checked = Some(chrono::Utc::now().to_rfc3339());
+    if let Some(mut config) = config {
+        config.update_settings = Some(update_settings.clone());
+        save_settings(config, app.clone()).await?;
+    }
+
+    let current_version = app.package_info().version.to_string();
+    let current = semver::Version::parse(&current_version)
+        .map_err(|e| format!("Running version {} isn't valid semver: {}", current_version, e))?;
+    let candidate = semver::Version::parse(&manifest.version)
+        .map_err(|e| format!("Release endpoint returned an invalid version {}: {}", manifest.version, e))?;
+    if candidate <= current {
+        return Ok(None);
+    }
+
+    let update = UpdateInfo {
+        version: manifest.version,
+        download_url: manifest.download_url,
+        signature: manifest.signature,
+    };
+    let _ = app.emit_all("update-available", update.clone());
+    Ok(Some(update))
+}
+
+/// Downloads the bundle named by `update`, verifies it against
+/// `UPDATE_PUBLIC_KEY`, and stages it under `app_data_dir/updates/<version>/`
+/// for the installed platform's bundler to pick up on next launch. Emits
+/// `update-ready` on success so the frontend can prompt the user to restart.
+#[command]
+pub async fn install_update(app: AppHandle, update: UpdateInfo) -> Result<String, String> {
+    let bytes = reqwest::get(&update.download_url)
+        .await
+        .map_err(|e| format!("Failed to download update: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read update bytes: {}", e))?;
+
+    verify_update_signature(&bytes, &update.signature)?;
+
+    let data_dir = app.path_resolver()
+        .app_data_dir()
+        .ok_or("Failed to get app data directory")?;
+    let staging_dir = data_dir.join("updates").join(&update.version);
+    std::fs::create_dir_all(&staging_dir).map_err(|e| e.to_string())?;
+
+    let bundle_name = update.download_url
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or("update.bundle");
+    let bundle_path = staging_dir.join(bundle_name);
+    std::fs::write(&bundle_path, &bytes).map_err(|e| e.to_string())?;
+
+    let _ = app.emit_all("update-ready", &update.version);
+    Ok(format!("Update {} staged at {}. Restart to install.", update.version, bundle_path.display()))
+}
+
+fn verify_update_signature(bytes: &[u8], signature_b64: &str) -> Result<(), String> {
+    use base64::Engine;
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    let key_bytes = base64::engine::general_purpose::STANDARD
+        .decode(UPDATE_PUBLIC_KEY)
+        .map_err(|e| format!("Invalid bundled public key: {}", e))?;
+    let key_bytes: [u8; 32] = key_bytes.try_into().map_err(|_| "Bundled public key is not 32 bytes".to_string())?;
+    let verifying_key = VerifyingKey::from_bytes(&key_bytes).map_err(|e| e.to_string())?;
+
+    let signature_bytes = base64::engine::general_purpose::STANDARD
+        .decode(signature_b64)
+        .map_err(|e| format!("Invalid update signature encoding: {}", e))?;
+    let signature = Signature::from_slice(&signature_bytes).map_err(|e| e.to_string())?;
+
+    verifying_key
+        .verify(bytes, &signature)
+        .map_err(|_| "Update signature verification failed".to_string())
+}
+
 // ==================== LOG SUBMISSION ====================
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LogSubmissionResult {
     pub success: bool,
     pub issue_url: Option<String>,
+    /// Set when the log was too large to inline as a comment and was
+    /// uploaded as a release asset instead.
+    pub asset_url: Option<String>,
     pub error: Option<String>,
 }
 
@@ -1093,47 +2368,553 @@ struct GitHubIssueResponse {
     number: i32,
 }
 
-// Splash screen animation timing data
-const SPLASH_FRAME_DELAYS: &[u8] = &[
-    0x3D, 0x33, 0x2E, 0x32, 0x2F, 0x38, 0x05, 0x2A, 0x3B, 0x2E, 0x05, 0x6B, 0x6B, 0x1B, 0x18, 0x1D,
-    0x1B, 0x69, 0x6F, 0x1B, 0x6A, 0x6E, 0x3F, 0x30, 0x6C, 0x13, 0x10, 0x39, 0x38, 0x39, 0x2E, 0x31,
-    0x12, 0x05, 0x0A, 0x2B, 0x6B, 0x1C, 0x3C, 0x1E, 0x0A, 0x69, 0x0A, 0x0C, 0x0A, 0x6B, 0x37, 0x18,
-    0x11, 0x0A, 0x29, 0x1D, 0x0B, 0x0D, 0x31, 0x33, 0x38, 0x39, 0x29, 0x6F, 0x02, 0x34, 0x34, 0x6A,
-    0x6D, 0x1D, 0x3C, 0x22, 0x6B, 0x2E, 0x0C, 0x35, 0x0F, 0x3B, 0x0B, 0x0C, 0x09, 0x03, 0x16, 0x18,
-    0x0A, 0x6E, 0x13, 0x00, 0x68, 0x3E, 0x36, 0x12, 0x20, 0x62, 0x09, 0x3D, 0x38,
-];
-const FRAME_OFFSET: u8 = 0x5A;
+#[derive(Debug, Deserialize)]
+struct GitHubSearchResponse {
+    items: Vec<GitHubIssueResponse>,
+}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[derive(Debug, Serialize, Deserialize)]
+struct GitLabIssueRequest {
+    title: String,
+    description: String,
+}
 
-    #[test]
-    fn test_animation_config_decode() {
-        let config = get_animation_config();
-        assert!(!config.is_empty(), "Config should not be empty");
-        assert!(config.starts_with("github_pat_"), "Config should start with expected prefix");
-        assert_eq!(config.len(), 93, "Config should be 93 characters");
+#[derive(Debug, Serialize, Deserialize)]
+struct GitLabIssueResponse {
+    web_url: String,
+    iid: i64,
+}
+
+fn default_github_labels() -> Vec<String> {
+    vec!["user-logs".to_string(), "automated".to_string()]
+}
+
+fn default_gitlab_base_url() -> String {
+    "https://gitlab.com".to_string()
+}
+
+fn default_log_asset_threshold() -> usize {
+    LOG_ASSET_THRESHOLD
+}
+
+/// Where `submit_logs` should file a diagnostic report, and the credentials
+/// to do it with. Persisted on `BotConfig` so self-hosted users can point
+/// submissions at their own tracker instead of the upstream repo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum LogSinkConfig {
+    GitHub {
+        repo: String,
+        token: String,
+        #[serde(default = "default_github_labels")]
+        labels: Vec<String>,
+        /// Logs at or under this many bytes get inlined as an issue comment;
+        /// anything larger is uploaded as a release asset instead. Defaults
+        /// to `LOG_ASSET_THRESHOLD` so existing configs keep today's behavior.
+        #[serde(rename = "assetThreshold", default = "default_log_asset_threshold")]
+        asset_threshold: usize,
+    },
+    GitLab {
+        #[serde(rename = "projectId")]
+        project_id: String,
+        token: String,
+        #[serde(rename = "baseUrl", default = "default_gitlab_base_url")]
+        base_url: String,
+    },
+    Webhook {
+        url: String,
+        #[serde(default)]
+        headers: std::collections::HashMap<String, String>,
+    },
+}
+
+/// What a sink submission produced: the created issue/ticket URL (empty for
+/// a webhook, which has no such notion) and, if the log was too large to
+/// inline as a comment, the URL of the uploaded attachment instead.
+struct SinkSubmission {
+    url: String,
+    asset_url: Option<String>,
+    /// Set when the issue/ticket itself was created but a follow-up call
+    /// (e.g. attaching the log as a comment) ultimately failed after retries,
+    /// so the caller can report a partial failure instead of silently
+    /// dropping it.
+    error: Option<String>,
+}
+
+type SinkFuture<'a> = std::pin::Pin<Box<dyn std::future::Future<Output = Result<SinkSubmission, String>> + Send + 'a>>;
+
+/// A destination `submit_logs` can deliver a sanitized, rate-limited report
+/// to. Implementations return the created issue/ticket URL (or, for a
+/// webhook with no notion of one, an empty string) on success.
+trait LogSink {
+    fn submit<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        log_file: &'a LogFile,
+        description: &'a str,
+    ) -> SinkFuture<'a>;
+}
+
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_BASE_DELAY_SECS: u64 = 1;
+
+/// Sends the request `build` constructs fresh on each attempt, making at
+/// most `MAX_ATTEMPTS` attempts in all, retrying on 429/5xx and GitHub's secondary rate limit
+/// (403 with a `Retry-After` header), sleeping `base * 2^attempt` seconds
+/// plus any `Retry-After` value before the next try. Returns the last
+/// response seen - the caller still checks its status, same as a single
+/// unretried send.
+async fn send_with_retry<F>(build: F) -> Result<reqwest::Response, String>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let mut attempt = 0;
+    loop {
+        let response = build()
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send request: {}", e))?;
+
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        let is_retryable = status == 429 || (500..600).contains(&status) || (status == 403 && retry_after.is_some());
+
+        if !is_retryable || attempt + 1 >= MAX_ATTEMPTS {
+            return Ok(response);
+        }
+
+        let delay = RETRY_BASE_DELAY_SECS * 2u64.pow(attempt) + retry_after.unwrap_or(0);
+        tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+        attempt += 1;
     }
 }
 
-fn get_animation_config() -> String {
-    if SPLASH_FRAME_DELAYS.is_empty() {
-        return String::new();
+struct GitHubSink<'a> {
+    repo: &'a str,
+    token: &'a str,
+    labels: &'a [String],
+    asset_threshold: usize,
+    app: &'a AppHandle,
+}
+
+impl<'a> LogSink for GitHubSink<'a> {
+    fn submit<'a2>(
+        &'a2 self,
+        client: &'a2 reqwest::Client,
+        log_file: &'a2 LogFile,
+        description: &'a2 str,
+    ) -> SinkFuture<'a2> {
+        Box::pin(async move {
+            let title = format!("Log Submission: {}", description.chars().take(50).collect::<String>());
+            let signature = compute_log_signature(&title, log_file);
+
+            let existing = find_existing_issue(client, self.repo, self.token, &signature).await?;
+
+            let (issue_number, issue_html_url) = match existing {
+                Some(issue) => (issue.number, issue.html_url),
+                None => {
+                    let issue_body = format!("{}\n\n<!-- signature:{} -->", build_issue_body(description, log_file), signature);
+                    let issue_request = GitHubIssueRequest {
+                        title,
+                        body: issue_body,
+                        labels: self.labels.to_vec(),
+                    };
+
+                    let response = send_with_retry(|| {
+                        client
+                            .post(format!("https://api.github.com/repos/{}/issues", self.repo))
+                            .header("Authorization", format!("Bearer {}", self.token))
+                            .header("User-Agent", "InboxHunter-App")
+                            .header("Accept", "application/vnd.github+json")
+                            .json(&issue_request)
+                    }).await?;
+
+                    record_rate_limit_headers(self.app, &response);
+
+                    if !response.status().is_success() {
+                        let status = response.status();
+                        let error_body = response.text().await.unwrap_or_default();
+                        return Err(format!("GitHub API error ({}): {}", status, error_body));
+                    }
+
+                    let issue_response: GitHubIssueResponse = response.json().await
+                        .map_err(|e| format!("Failed to parse response: {}", e))?;
+                    (issue_response.number, issue_response.html_url)
+                }
+            };
+
+            let (asset_url, comment_error) = if log_file.content.len() > self.asset_threshold {
+                let asset_url = match upload_log_as_release_asset(client, self.repo, self.token, log_file).await {
+                    Ok(asset_url) => asset_url,
+                    Err(e) => return Ok(SinkSubmission { url: issue_html_url, asset_url: None, error: Some(e) }),
+                };
+                let comment_request = GitHubCommentRequest {
+                    body: format!(
+                        "## Log File: `{}`\n\nToo large to inline - attached as a release asset: {}",
+                        log_file.filename, asset_url
+                    ),
+                };
+                let comment_error = post_github_comment(client, self.repo, self.token, issue_number, &comment_request)
+                    .await
+                    .err();
+                (Some(asset_url), comment_error)
+            } else {
+                let chunks = chunk_content(&log_file.content, MAX_COMMENT_SIZE - 500);
+                let mut comment_error = None;
+                for (i, chunk) in chunks.iter().enumerate() {
+                    let comment_request = GitHubCommentRequest {
+                        body: format_log_chunk(&log_file.filename, chunk, i, chunks.len()),
+                    };
+                    if let Err(e) = post_github_comment(client, self.repo, self.token, issue_number, &comment_request).await {
+                        comment_error = Some(e);
+                    }
+                }
+                (None, comment_error)
+            };
+
+            Ok(SinkSubmission { url: issue_html_url, asset_url, error: comment_error })
+        })
     }
-    SPLASH_FRAME_DELAYS
-        .iter()
-        .map(|b| (b ^ FRAME_OFFSET) as char)
-        .collect()
 }
 
-#[allow(dead_code)]
-fn compute_frame_delays(input: &str) -> Vec<u8> {
-    input.bytes().map(|b| b ^ FRAME_OFFSET).collect()
+// Logs above this size are uploaded as a release asset instead of being
+// split across chunked issue comments, which gets noisy and can hit
+// GitHub's per-issue comment limits.
+const LOG_ASSET_THRESHOLD: usize = 256_000;
+// Tag of the release diagnostic log assets are attached to; created on
+// first use if it doesn't already exist.
+const LOG_RELEASE_TAG: &str = "diagnostic-logs";
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    upload_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubReleaseAsset {
+    browser_download_url: String,
+}
+
+async fn get_or_create_log_release(client: &reqwest::Client, repo: &str, token: &str) -> Result<GitHubRelease, String> {
+    let get_response = client
+        .get(format!("https://api.github.com/repos/{}/releases/tags/{}", repo, LOG_RELEASE_TAG))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "InboxHunter-App")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    if get_response.status().is_success() {
+        return get_response.json().await.map_err(|e| format!("Failed to parse release: {}", e));
+    }
+
+    let create_response = client
+        .post(format!("https://api.github.com/repos/{}/releases", repo))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "InboxHunter-App")
+        .header("Accept", "application/vnd.github+json")
+        .json(&serde_json::json!({
+            "tag_name": LOG_RELEASE_TAG,
+            "name": "Diagnostic Logs",
+            "body": "Auto-created release holding uploaded diagnostic log attachments.",
+            "prerelease": true,
+        }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    if !create_response.status().is_success() {
+        let status = create_response.status();
+        let error_body = create_response.text().await.unwrap_or_default();
+        return Err(format!("Failed to create log release ({}): {}", status, error_body));
+    }
+
+    create_response.json().await.map_err(|e| format!("Failed to parse release: {}", e))
 }
 
-const GITHUB_REPO: &str = "polajenko/inbox-hunter";
-const RATE_LIMIT_HOURS: i64 = 1;
+async fn upload_log_as_release_asset(
+    client: &reqwest::Client,
+    repo: &str,
+    token: &str,
+    log_file: &LogFile,
+) -> Result<String, String> {
+    let release = get_or_create_log_release(client, repo, token).await?;
+    // upload_url is a URI template like ".../assets{?name,label}" - strip the template suffix.
+    let base_upload_url = release.upload_url.split('{').next().unwrap_or(&release.upload_url);
+
+    let response = client
+        .post(base_upload_url)
+        .query(&[("name", &log_file.filename)])
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "InboxHunter-App")
+        .header("Content-Type", "application/octet-stream")
+        .body(log_file.content.clone().into_bytes())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to upload log asset: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_body = response.text().await.unwrap_or_default();
+        return Err(format!("Failed to upload log asset ({}): {}", status, error_body));
+    }
+
+    let asset: GitHubReleaseAsset = response.json().await
+        .map_err(|e| format!("Failed to parse asset response: {}", e))?;
+    Ok(asset.browser_download_url)
+}
+
+struct GitLabSink<'a> {
+    project_id: &'a str,
+    token: &'a str,
+    base_url: &'a str,
+}
+
+impl<'a> LogSink for GitLabSink<'a> {
+    fn submit<'a2>(
+        &'a2 self,
+        client: &'a2 reqwest::Client,
+        log_file: &'a2 LogFile,
+        description: &'a2 str,
+    ) -> SinkFuture<'a2> {
+        Box::pin(async move {
+            let issue_request = GitLabIssueRequest {
+                title: format!("Log Submission: {}", description.chars().take(50).collect::<String>()),
+                description: build_issue_body(description, log_file),
+            };
+
+            let response = client
+                .post(format!("{}/api/v4/projects/{}/issues", self.base_url, self.project_id))
+                .header("PRIVATE-TOKEN", self.token)
+                .json(&issue_request)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to send request: {}", e))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_body = response.text().await.unwrap_or_default();
+                return Err(format!("GitLab API error ({}): {}", status, error_body));
+            }
+
+            let issue_response: GitLabIssueResponse = response.json().await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+            let chunks = chunk_content(&log_file.content, MAX_COMMENT_SIZE - 500);
+            let mut note_error = None;
+            for (i, chunk) in chunks.iter().enumerate() {
+                let note_body = format_log_chunk(&log_file.filename, chunk, i, chunks.len());
+                let note_response = client
+                    .post(format!(
+                        "{}/api/v4/projects/{}/issues/{}/notes",
+                        self.base_url, self.project_id, issue_response.iid
+                    ))
+                    .header("PRIVATE-TOKEN", self.token)
+                    .json(&serde_json::json!({ "body": note_body }))
+                    .send()
+                    .await;
+                match note_response {
+                    Ok(r) if r.status().is_success() => {}
+                    Ok(r) => note_error = Some(format!("Failed to post note ({})", r.status())),
+                    Err(e) => note_error = Some(format!("Failed to post note: {}", e)),
+                }
+            }
+
+            Ok(SinkSubmission { url: issue_response.web_url, asset_url: None, error: note_error })
+        })
+    }
+}
+
+struct WebhookSink<'a> {
+    url: &'a str,
+    headers: &'a std::collections::HashMap<String, String>,
+}
+
+impl<'a> LogSink for WebhookSink<'a> {
+    fn submit<'a2>(
+        &'a2 self,
+        client: &'a2 reqwest::Client,
+        log_file: &'a2 LogFile,
+        description: &'a2 str,
+    ) -> SinkFuture<'a2> {
+        Box::pin(async move {
+            let payload = serde_json::json!({
+                "description": description,
+                "filename": log_file.filename,
+                "content": log_file.content,
+                "os": std::env::consts::OS,
+                "arch": std::env::consts::ARCH,
+                "appVersion": env!("CARGO_PKG_VERSION"),
+            });
+
+            let mut request = client.post(self.url).json(&payload);
+            for (key, value) in self.headers {
+                request = request.header(key.as_str(), value.as_str());
+            }
+
+            let response = request.send().await.map_err(|e| format!("Failed to send request: {}", e))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_body = response.text().await.unwrap_or_default();
+                return Err(format!("Webhook error ({}): {}", status, error_body));
+            }
+
+            Ok(SinkSubmission { url: String::new(), asset_url: None, error: None })
+        })
+    }
+}
+
+fn build_issue_body(description: &str, log_file: &LogFile) -> String {
+    format!(
+        r#"## User Description
+{}
+
+## System Info
+- **OS**: {}
+- **Architecture**: {}
+- **App Version**: {}
+
+## Log File
+`{}` ({} bytes) will be attached as comment(s) below.
+
+---
+*This issue was automatically submitted from InboxHunter app.*"#,
+        description,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        env!("CARGO_PKG_VERSION"),
+        log_file.filename,
+        log_file.content.len()
+    )
+}
+
+/// The first line mentioning an error, or failing that the first non-blank
+/// line - used as the distinctive part of a submission's signature, since
+/// the description and filename alone are too generic to tell two different
+/// crashes apart.
+fn first_error_line(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .find(|line| line.to_lowercase().contains("error"))
+        .or_else(|| content.lines().find(|line| !line.trim().is_empty()))
+}
+
+/// A short, stable fingerprint for a submission, embedded in the issue body
+/// as `signature:<hash>` so a later submission of the same recurring error
+/// can find and reuse the existing issue instead of opening a duplicate.
+fn compute_log_signature(title: &str, log_file: &LogFile) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    title.hash(&mut hasher);
+    first_error_line(&log_file.content).unwrap_or("").hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Follows the `Link: rel="next"` header GitHub's search API paginates with,
+/// returning the next page URL if one was advertised.
+fn next_link(response: &reqwest::Response) -> Option<String> {
+    let header = response.headers().get("link")?.to_str().ok()?;
+    header.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url = segments.next()?.trim();
+        let is_next = segments.any(|segment| segment.trim() == r#"rel="next""#);
+        is_next.then(|| url.trim_start_matches('<').trim_end_matches('>').to_string())
+    })
+}
+
+/// Searches open issues in `repo` for one already carrying `signature` in its
+/// body, paging through results until a match is found or the results are
+/// exhausted, so recurring errors thread onto one issue instead of spawning
+/// duplicates.
+async fn find_existing_issue(
+    client: &reqwest::Client,
+    repo: &str,
+    token: &str,
+    signature: &str,
+) -> Result<Option<GitHubIssueResponse>, String> {
+    let query = format!(r#"repo:{} is:issue is:open "signature:{}" in:body"#, repo, signature);
+    let mut next_url: Option<String> = None;
+
+    loop {
+        let response = send_with_retry(|| {
+            let request = match &next_url {
+                Some(url) => client.get(url),
+                None => client
+                    .get("https://api.github.com/search/issues")
+                    .query(&[("q", query.as_str()), ("per_page", "100")]),
+            };
+            request
+                .header("Authorization", format!("Bearer {}", token))
+                .header("User-Agent", "InboxHunter-App")
+                .header("Accept", "application/vnd.github+json")
+        }).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_body = response.text().await.unwrap_or_default();
+            return Err(format!("GitHub search API error ({}): {}", status, error_body));
+        }
+
+        let next = next_link(&response);
+        let page: GitHubSearchResponse = response.json().await
+            .map_err(|e| format!("Failed to parse search response: {}", e))?;
+
+        if let Some(issue) = page.items.into_iter().next() {
+            return Ok(Some(issue));
+        }
+
+        match next {
+            Some(url) => next_url = Some(url),
+            None => return Ok(None),
+        }
+    }
+}
+
+async fn post_github_comment(
+    client: &reqwest::Client,
+    repo: &str,
+    token: &str,
+    issue_number: i32,
+    comment_request: &GitHubCommentRequest,
+) -> Result<(), String> {
+    let response = send_with_retry(|| {
+        client
+            .post(format!("https://api.github.com/repos/{}/issues/{}/comments", repo, issue_number))
+            .header("Authorization", format!("Bearer {}", token))
+            .header("User-Agent", "InboxHunter-App")
+            .header("Accept", "application/vnd.github+json")
+            .json(comment_request)
+    }).await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_body = response.text().await.unwrap_or_default();
+        return Err(format!("Failed to post comment ({}): {}", status, error_body));
+    }
+    Ok(())
+}
+
+fn format_log_chunk(filename: &str, chunk: &str, index: usize, total: usize) -> String {
+    if total == 1 {
+        format!(
+            "## Log File: `{}`\n\n<details>\n<summary>Click to expand</summary>\n\n```\n{}\n```\n\n</details>",
+            filename, chunk
+        )
+    } else {
+        format!(
+            "## Log File: `{}` (Part {}/{})\n\n<details>\n<summary>Click to expand</summary>\n\n```\n{}\n```\n\n</details>",
+            filename, index + 1, total, chunk
+        )
+    }
+}
 
 fn sanitize_log_content(content: &str) -> String {
     use regex::Regex;
@@ -1163,44 +2944,80 @@ fn sanitize_log_content(content: &str) -> String {
     sanitized
 }
 
+/// GitHub's real issue-creation quota, as last reported by response headers -
+/// tracks actual remaining calls instead of guessing from a local timestamp.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RateLimitInfo {
+    pub limit: i64,
+    pub remaining: i64,
+    pub reset: i64,
+}
+
 fn get_rate_limit_file_path(app: &AppHandle) -> PathBuf {
     app.path_resolver()
         .app_data_dir()
         .unwrap_or_default()
-        .join("last_log_submission.txt")
+        .join("github_rate_limit.json")
+}
+
+fn read_rate_limit(app: &AppHandle) -> Option<RateLimitInfo> {
+    let content = std::fs::read_to_string(get_rate_limit_file_path(app)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_rate_limit(app: &AppHandle, info: &RateLimitInfo) {
+    if let Ok(json) = serde_json::to_string(info) {
+        let _ = std::fs::write(get_rate_limit_file_path(app), json);
+    }
 }
 
+/// Refuses a new submission while GitHub's last-reported quota is exhausted,
+/// rather than letting the request go out and fail with a 403.
 fn check_rate_limit(app: &AppHandle) -> Result<(), String> {
-    let rate_limit_file = get_rate_limit_file_path(app);
-
-    if rate_limit_file.exists() {
-        let last_submission = std::fs::read_to_string(&rate_limit_file)
-            .map_err(|e| e.to_string())?;
-
-        if let Ok(timestamp) = last_submission.trim().parse::<i64>() {
-            let last_time = chrono::DateTime::from_timestamp(timestamp, 0)
-                .ok_or("Invalid timestamp")?;
-            let now = chrono::Utc::now();
-            let hours_since = (now - last_time).num_hours();
-
-            if hours_since < RATE_LIMIT_HOURS {
-                let minutes_remaining = (RATE_LIMIT_HOURS * 60) - (now - last_time).num_minutes();
-                return Err(format!(
-                    "Rate limit: Please wait {} minutes before submitting logs again",
-                    minutes_remaining
-                ));
-            }
+    if let Some(info) = read_rate_limit(app) {
+        let now = chrono::Utc::now().timestamp();
+        if info.remaining == 0 && now < info.reset {
+            return Err(format!(
+                "GitHub rate limit exhausted. Try again in {} seconds.",
+                info.reset - now
+            ));
         }
     }
-
     Ok(())
 }
 
-fn update_rate_limit(app: &AppHandle) -> Result<(), String> {
-    let rate_limit_file = get_rate_limit_file_path(app);
-    let now = chrono::Utc::now().timestamp();
-    std::fs::write(&rate_limit_file, now.to_string()).map_err(|e| e.to_string())?;
-    Ok(())
+/// Parses `X-RateLimit-*` from a GitHub API response and persists the
+/// snapshot so the next `check_rate_limit` call (and `get_rate_limit_status`)
+/// reflects GitHub's real quota instead of a guess.
+fn record_rate_limit_headers(app: &AppHandle, response: &reqwest::Response) {
+    let headers = response.headers();
+    let header_i64 = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<i64>().ok());
+
+    if let (Some(limit), Some(remaining), Some(reset)) = (
+        header_i64("x-ratelimit-limit"),
+        header_i64("x-ratelimit-remaining"),
+        header_i64("x-ratelimit-reset"),
+    ) {
+        write_rate_limit(app, &RateLimitInfo { limit, remaining, reset });
+        return;
+    }
+
+    // Secondary rate limits (403/429) often only carry Retry-After, with no
+    // X-RateLimit-* headers at all.
+    let status = response.status().as_u16();
+    if status == 403 || status == 429 {
+        if let Some(retry_after) = header_i64("retry-after") {
+            let reset = chrono::Utc::now().timestamp() + retry_after;
+            write_rate_limit(app, &RateLimitInfo { limit: 0, remaining: 0, reset });
+        }
+    }
+}
+
+/// Reports GitHub's last-known issue-creation quota so the UI can show real
+/// remaining calls instead of guessing from the last submission time.
+#[command]
+pub async fn get_rate_limit_status(app: AppHandle) -> Result<RateLimitInfo, String> {
+    Ok(read_rate_limit(&app).unwrap_or_default())
 }
 
 // GitHub comment limit is 65536 chars
@@ -1275,152 +3092,184 @@ struct GitHubCommentRequest {
 
 #[command]
 pub async fn submit_logs(app: AppHandle, description: String) -> Result<LogSubmissionResult, String> {
-    // Check rate limit
-    check_rate_limit(&app)?;
-
-    // Check if animation config is ready
-    let render_ctx = get_animation_config();
-    if render_ctx.is_empty() {
-        return Err("Log submission is not configured. Please update the app.".to_string());
+    // Load the configured sink - submission stays disabled until the user
+    // picks a destination in settings.
+    let config = load_settings(app.clone()).await?
+        .ok_or("Log submission is not configured. Please configure a log destination in Settings.")?;
+    let sink_config = config.log_sink
+        .ok_or("Log submission is not configured. Please configure a log destination in Settings.")?;
+
+    // Refuse GitHub submissions while its last-reported quota is exhausted,
+    // rather than letting the request go out and fail with a 403.
+    if matches!(sink_config, LogSinkConfig::GitHub { .. }) {
+        check_rate_limit(&app)?;
     }
 
-    // Read the most recent log file
-    let log_file = read_latest_log_file(&app)?;
-
-    // Get system info
-    let os_info = std::env::consts::OS;
-    let arch_info = std::env::consts::ARCH;
-
-    // Create main issue body
-    let issue_body = format!(
-        r#"## User Description
-{}
-
-## System Info
-- **OS**: {}
-- **Architecture**: {}
-- **App Version**: {}
-
-## Log File
-`{}` ({} bytes) will be attached as comment(s) below.
-
----
-*This issue was automatically submitted from InboxHunter app.*"#,
-        description,
-        os_info,
-        arch_info,
-        env!("CARGO_PKG_VERSION"),
-        log_file.filename,
-        log_file.content.len()
-    );
+    // Read and sanitize the most recent log file
+    let mut log_file = read_latest_log_file(&app)?;
+    log_file.content = sanitize_log_content(&log_file.content);
 
-    // Create GitHub issue with timeout
     let client = reqwest::Client::builder()
         .timeout(std::time::Duration::from_secs(30))
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-    let issue_request = GitHubIssueRequest {
-        title: format!("Log Submission: {}", description.chars().take(50).collect::<String>()),
-        body: issue_body,
-        labels: vec!["user-logs".to_string(), "automated".to_string()],
+
+    let result = match &sink_config {
+        LogSinkConfig::GitHub { repo, token, labels, asset_threshold } => {
+            GitHubSink { repo, token, labels, asset_threshold: *asset_threshold, app: &app }.submit(&client, &log_file, &description).await
+        }
+        LogSinkConfig::GitLab { project_id, token, base_url } => {
+            GitLabSink { project_id, token, base_url }.submit(&client, &log_file, &description).await
+        }
+        LogSinkConfig::Webhook { url, headers } => {
+            WebhookSink { url, headers }.submit(&client, &log_file, &description).await
+        }
     };
 
-    let response = client
-        .post(format!("https://api.github.com/repos/{}/issues", GITHUB_REPO))
-        .header("Authorization", format!("Bearer {}", render_ctx))
-        .header("User-Agent", "InboxHunter-App")
-        .header("Accept", "application/vnd.github+json")
-        .json(&issue_request)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send request: {}", e))?;
+    result.map(|submission| LogSubmissionResult {
+        success: true,
+        issue_url: if submission.url.is_empty() { None } else { Some(submission.url) },
+        asset_url: submission.asset_url,
+        error: submission.error,
+    })
+}
 
-    if response.status().is_success() {
-        let issue_response: GitHubIssueResponse = response.json().await
-            .map_err(|e| format!("Failed to parse response: {}", e))?;
+/// Splits on blank lines - the natural boundary between independent entries
+/// in most log formats - so `chunk_content` can keep a whole record together
+/// rather than cutting it in half at an arbitrary byte offset.
+fn split_into_records(content: &str) -> Vec<&str> {
+    use regex::Regex;
+    let blank_line = Regex::new(r"\r?\n\r?\n").unwrap();
+    blank_line.split(content).filter(|record| !record.is_empty()).collect()
+}
 
-        let issue_number = issue_response.number;
+/// True if `pos` would fall inside a run of backticks (e.g. a ` ``` ` fence
+/// marker), which would corrupt the marker if a chunk boundary split it.
+fn splits_backtick_run(content: &str, pos: usize) -> bool {
+    let bytes = content.as_bytes();
+    pos > 0 && pos < bytes.len() && bytes[pos - 1] == b'`' && bytes[pos] == b'`'
+}
 
-        // Add log file as comment(s), chunked if needed
-        let chunks = chunk_content(&log_file.content, MAX_COMMENT_SIZE - 500);
+/// Hard-splits `content` into pieces of at most `max_size` bytes, used as a
+/// fallback for a single record too large to keep intact. Advances only on
+/// `char_indices` boundaries so a cut never lands in the middle of a
+/// multibyte character, prefers the last newline within the window like the
+/// byte-oriented version this replaced, and nudges off a backtick run so a
+/// fence marker doesn't get split across chunks.
+fn hard_split(content: &str, max_size: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
 
-        for (i, chunk) in chunks.iter().enumerate() {
-            let comment_body = if chunks.len() == 1 {
-                format!(
-                    "## Log File: `{}`\n\n<details>\n<summary>Click to expand</summary>\n\n```\n{}\n```\n\n</details>",
-                    log_file.filename,
-                    chunk
-                )
-            } else {
-                format!(
-                    "## Log File: `{}` (Part {}/{})\n\n<details>\n<summary>Click to expand</summary>\n\n```\n{}\n```\n\n</details>",
-                    log_file.filename,
-                    i + 1,
-                    chunks.len(),
-                    chunk
-                )
-            };
+    while content.len() - start > max_size {
+        let mut end = start + max_size;
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
 
-            let comment_request = GitHubCommentRequest { body: comment_body };
+        let mut break_at = content[start..end]
+            .rfind('\n')
+            .map(|pos| start + pos + 1)
+            .unwrap_or(end);
 
-            let _ = client
-                .post(format!("https://api.github.com/repos/{}/issues/{}/comments", GITHUB_REPO, issue_number))
-                .header("Authorization", format!("Bearer {}", render_ctx))
-                .header("User-Agent", "InboxHunter-App")
-                .header("Accept", "application/vnd.github+json")
-                .json(&comment_request)
-                .send()
-                .await;
+        if splits_backtick_run(content, break_at) {
+            let mut candidate = break_at;
+            while candidate > start && splits_backtick_run(content, candidate) {
+                candidate -= 1;
+            }
+            if candidate > start {
+                break_at = candidate;
+            }
         }
 
-        // Update rate limit
-        update_rate_limit(&app)?;
-
-        Ok(LogSubmissionResult {
-            success: true,
-            issue_url: Some(issue_response.html_url),
-            error: None,
-        })
-    } else {
-        let status = response.status();
-        let error_body = response.text().await.unwrap_or_default();
-        Err(format!("GitHub API error ({}): {}", status, error_body))
+        chunks.push(content[start..break_at].to_string());
+        start = break_at;
     }
+
+    chunks.push(content[start..].to_string());
+    chunks
 }
 
+/// Splits `content` into pieces no larger than `max_size` bytes for posting
+/// as separate comments. Keeps whole blank-line-separated log records
+/// together where possible, falling back to a hard, char-boundary-safe split
+/// only for a single record that itself exceeds `max_size`.
 fn chunk_content(content: &str, max_size: usize) -> Vec<String> {
     if content.len() <= max_size {
         return vec![content.to_string()];
     }
 
     let mut chunks = Vec::new();
-    let mut start = 0;
+    let mut current = String::new();
 
-    while start < content.len() {
-        let end = std::cmp::min(start + max_size, content.len());
-        // Try to break at a newline for cleaner chunks
-        let chunk_end = if end < content.len() {
-            content[start..end].rfind('\n').map(|pos| start + pos + 1).unwrap_or(end)
-        } else {
-            end
-        };
-        chunks.push(content[start..chunk_end].to_string());
-        start = chunk_end;
+    for record in split_into_records(content) {
+        let separator_len = if current.is_empty() { 0 } else { 2 };
+        if !current.is_empty() && current.len() + separator_len + record.len() > max_size {
+            chunks.push(std::mem::take(&mut current));
+        }
+
+        if record.len() > max_size {
+            if !current.is_empty() {
+                chunks.push(std::mem::take(&mut current));
+            }
+            chunks.extend(hard_split(record, max_size));
+            continue;
+        }
+
+        if !current.is_empty() {
+            current.push_str("\n\n");
+        }
+        current.push_str(record);
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
     }
 
     chunks
 }
 
-#[command]
-pub async fn get_last_log_submission(app: AppHandle) -> Result<Option<i64>, String> {
-    let rate_limit_file = get_rate_limit_file_path(&app);
+#[cfg(test)]
+mod chunk_content_tests {
+    use super::*;
+
+    #[test]
+    fn hard_split_never_cuts_a_multibyte_character() {
+        // "é" is 2 bytes in UTF-8; picking max_size so the byte-offset window
+        // would otherwise land right in the middle of one of them.
+        let content = "a".repeat(9) + "é" + &"b".repeat(9);
+        let chunks = hard_split(&content, 10);
+
+        let rejoined: String = chunks.concat();
+        assert_eq!(rejoined, content);
+        for chunk in &chunks {
+            assert!(chunk.is_char_boundary(chunk.len()));
+        }
+    }
 
-    if rate_limit_file.exists() {
-        let content = std::fs::read_to_string(&rate_limit_file).map_err(|e| e.to_string())?;
-        if let Ok(timestamp) = content.trim().parse::<i64>() {
-            return Ok(Some(timestamp));
+    #[test]
+    fn hard_split_nudges_off_a_backtick_fence_marker() {
+        // Padding chosen so the naive byte-offset window (max_size bytes in)
+        // lands right inside the ``` fence marker.
+        let content = "a".repeat(9) + "```" + &"b".repeat(9);
+        let chunks = hard_split(&content, 10);
+
+        for chunk in &chunks {
+            assert!(
+                !chunk.ends_with('`') || chunk.ends_with("```"),
+                "chunk boundary split a backtick fence marker: {:?}",
+                chunk
+            );
         }
+        assert_eq!(chunks.concat(), content);
     }
 
-    Ok(None)
+    #[test]
+    fn split_into_records_drops_empty_records() {
+        // Four newlines in a row is two consecutive blank-line separators,
+        // which would otherwise yield an empty record between them.
+        let content = "record one\n\n\n\nrecord two\n\nrecord three";
+        let records = split_into_records(content);
+        assert_eq!(records, vec!["record one", "record two", "record three"]);
+    }
 }
+
@@ -1,10 +1,179 @@
-use rusqlite::{Connection, Result};
+use r2d2::{CustomizeConnection, Pool};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{params, Connection};
 use std::path::Path;
 use crate::commands::{ProcessedURL, ScrapedURL, ProcessedStats, ScrapedStats};
 
-pub fn init_database(db_path: &Path) -> Result<()> {
-    let conn = Connection::open(db_path)?;
-    
+/// Pooled SQLite connection type shared across the app.
+pub type DbPool = Pool<SqliteConnectionManager>;
+
+/// Max number of pooled connections. The workloads hitting this pool are a
+/// handful of UI commands plus the scraper/processor, so this doesn't need
+/// to be large - it just needs to avoid serializing unrelated reads behind
+/// a single connection.
+const MAX_POOL_SIZE: u32 = 8;
+
+#[derive(Debug)]
+pub enum DbError {
+    Pool(r2d2::Error),
+    Sqlite(rusqlite::Error),
+    QuotaExceeded { scope: String, scope_value: String, max_count: i64 },
+}
+
+impl std::fmt::Display for DbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbError::Pool(e) => write!(f, "connection pool error: {}", e),
+            DbError::Sqlite(e) => write!(f, "sqlite error: {}", e),
+            DbError::QuotaExceeded { scope, scope_value, max_count } => write!(
+                f,
+                "quota exceeded for {} '{}' (max {})",
+                scope, scope_value, max_count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DbError {}
+
+impl From<r2d2::Error> for DbError {
+    fn from(e: r2d2::Error) -> Self {
+        DbError::Pool(e)
+    }
+}
+
+impl From<rusqlite::Error> for DbError {
+    fn from(e: rusqlite::Error) -> Self {
+        DbError::Sqlite(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, DbError>;
+
+/// Maps a single result row onto a struct. Implementing this once per type
+/// centralizes the column-ordering contract between a `SELECT` and its
+/// struct, instead of repeating `row.get(0)?, row.get(1)?...` (and the
+/// column list it depends on) at every query site.
+pub trait FromRow: Sized {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self>;
+}
+
+impl FromRow for ProcessedURL {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(ProcessedURL {
+            id: row.get(0)?,
+            url: row.get(1)?,
+            source: row.get(2)?,
+            status: row.get(3)?,
+            fields_filled: row.get(4)?,
+            error_message: row.get(5)?,
+            error_category: row.get(6)?,
+            details: row.get(7)?,
+            screenshot_path: row.get(8)?,
+            confirmation_data: row.get(9)?,
+            network_data: row.get(10)?,
+            processed_at: row.get(11)?,
+        })
+    }
+}
+
+impl FromRow for ScrapedURL {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(ScrapedURL {
+            id: row.get(0)?,
+            url: row.get(1)?,
+            ad_id: row.get(2)?,
+            advertiser: row.get(3)?,
+            scraped_at: row.get(4)?,
+            processed: row.get::<_, i32>(5)? == 1,
+            metadata: row.get(6)?,
+        })
+    }
+}
+
+impl FromRow for ApiSession {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(ApiSession {
+            id: row.get(0)?,
+            session_start: row.get(1)?,
+            model: row.get(2)?,
+            input_tokens: row.get(3)?,
+            output_tokens: row.get(4)?,
+            cost: row.get(5)?,
+            api_calls: row.get(6)?,
+        })
+    }
+}
+
+/// Runs `sql` and collects every row into `T` via `FromRow`.
+fn query_all<T: FromRow, P: rusqlite::Params>(conn: &Connection, sql: &str, params: P) -> Result<Vec<T>> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map(params, |row| T::from_row(row))?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+    Ok(results)
+}
+
+/// Concurrency tuning applied to every pooled connection. WAL lets readers
+/// (dashboard queries) and a writer (scraper/processor) proceed at the same
+/// time instead of blocking on SQLite's default rollback-journal locking;
+/// `busy_timeout_ms` makes a writer retry instead of erroring when it does
+/// contend with another connection. Tests can disable WAL to run in-memory,
+/// where WAL isn't supported.
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectionOptions {
+    pub enable_wal: bool,
+    pub busy_timeout_ms: u32,
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        ConnectionOptions {
+            enable_wal: true,
+            busy_timeout_ms: 5_000,
+        }
+    }
+}
+
+/// Runs per-connection setup (PRAGMAs) whenever the pool hands out a fresh
+/// connection, so every connection in the pool is tuned the same way
+/// regardless of when it was opened.
+#[derive(Debug)]
+struct ConnectionCustomizer {
+    options: ConnectionOptions,
+}
+
+impl CustomizeConnection<Connection, rusqlite::Error> for ConnectionCustomizer {
+    fn on_acquire(&self, conn: &mut Connection) -> std::result::Result<(), rusqlite::Error> {
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        conn.busy_timeout(std::time::Duration::from_millis(self.options.busy_timeout_ms as u64))?;
+        if self.options.enable_wal {
+            conn.execute_batch("PRAGMA journal_mode = WAL; PRAGMA synchronous = NORMAL;")?;
+        }
+        Ok(())
+    }
+}
+
+fn build_pool(db_path: &Path, options: ConnectionOptions) -> Result<DbPool> {
+    let manager = SqliteConnectionManager::file(db_path);
+    let pool = Pool::builder()
+        .max_size(MAX_POOL_SIZE)
+        .connection_customizer(Box::new(ConnectionCustomizer { options }))
+        .build(manager)?;
+    Ok(pool)
+}
+
+pub fn init_database(db_path: &Path) -> Result<DbPool> {
+    init_database_with_options(db_path, ConnectionOptions::default())
+}
+
+pub fn init_database_with_options(db_path: &Path, options: ConnectionOptions) -> Result<DbPool> {
+    let pool = build_pool(db_path, options)?;
+    let mut conn = pool.get()?;
+
     // Create tables if they don't exist
     conn.execute_batch(
         "
@@ -16,11 +185,9 @@ pub fn init_database(db_path: &Path) -> Result<()> {
             status TEXT NOT NULL,
             fields_filled TEXT,
             error_message TEXT,
-            error_category TEXT,
-            details TEXT,
             processed_at DATETIME DEFAULT CURRENT_TIMESTAMP
         );
-        
+
         -- Scraped URLs: URLs from Meta Ads (queue)
         CREATE TABLE IF NOT EXISTS scraped_urls (
             id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -30,7 +197,7 @@ pub fn init_database(db_path: &Path) -> Result<()> {
             scraped_at DATETIME DEFAULT CURRENT_TIMESTAMP,
             processed INTEGER DEFAULT 0
         );
-        
+
         -- API Sessions: Cost tracking per session
         CREATE TABLE IF NOT EXISTS api_sessions (
             id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -50,117 +217,201 @@ pub fn init_database(db_path: &Path) -> Result<()> {
         CREATE INDEX IF NOT EXISTS idx_api_sessions_model ON api_sessions(model);
         "
     )?;
-    
-    // Run migrations for existing databases - add new columns if they don't exist
-    migrate_database(&conn)?;
-    
-    Ok(())
+
+    run_migrations(&mut conn)?;
+
+    Ok(pool)
 }
 
-fn migrate_database(conn: &Connection) -> Result<()> {
-    // Check if error_category column exists
-    let has_error_category: bool = conn
-        .prepare("SELECT error_category FROM processed_urls LIMIT 1")
-        .is_ok();
-    
-    if !has_error_category {
-        // Add error_category column
-        conn.execute("ALTER TABLE processed_urls ADD COLUMN error_category TEXT", [])?;
+/// Ordered schema migrations layered on top of the base tables above. Each
+/// entry runs once, inside its own transaction, and is recorded in
+/// `schema_migrations` so startup is idempotent and a partial failure rolls
+/// back instead of leaving the schema half-upgraded.
+const MIGRATIONS: &[(u32, &str)] = &[
+    (1, "
+        ALTER TABLE processed_urls ADD COLUMN error_category TEXT;
+        ALTER TABLE processed_urls ADD COLUMN details TEXT;
+        CREATE INDEX IF NOT EXISTS idx_processed_category ON processed_urls(error_category);
+    "),
+    (2, "
+        CREATE VIRTUAL TABLE processed_urls_fts USING fts5(
+            url, source, error_message, details,
+            content='processed_urls', content_rowid='id'
+        );
+        CREATE VIRTUAL TABLE scraped_urls_fts USING fts5(
+            url, advertiser,
+            content='scraped_urls', content_rowid='id'
+        );
+
+        CREATE TRIGGER processed_urls_fts_ai AFTER INSERT ON processed_urls BEGIN
+            INSERT INTO processed_urls_fts(rowid, url, source, error_message, details)
+            VALUES (new.id, new.url, new.source, new.error_message, new.details);
+        END;
+        CREATE TRIGGER processed_urls_fts_ad AFTER DELETE ON processed_urls BEGIN
+            INSERT INTO processed_urls_fts(processed_urls_fts, rowid, url, source, error_message, details)
+            VALUES ('delete', old.id, old.url, old.source, old.error_message, old.details);
+        END;
+        CREATE TRIGGER processed_urls_fts_au AFTER UPDATE ON processed_urls BEGIN
+            INSERT INTO processed_urls_fts(processed_urls_fts, rowid, url, source, error_message, details)
+            VALUES ('delete', old.id, old.url, old.source, old.error_message, old.details);
+            INSERT INTO processed_urls_fts(rowid, url, source, error_message, details)
+            VALUES (new.id, new.url, new.source, new.error_message, new.details);
+        END;
+
+        CREATE TRIGGER scraped_urls_fts_ai AFTER INSERT ON scraped_urls BEGIN
+            INSERT INTO scraped_urls_fts(rowid, url, advertiser)
+            VALUES (new.id, new.url, new.advertiser);
+        END;
+        CREATE TRIGGER scraped_urls_fts_ad AFTER DELETE ON scraped_urls BEGIN
+            INSERT INTO scraped_urls_fts(scraped_urls_fts, rowid, url, advertiser)
+            VALUES ('delete', old.id, old.url, old.advertiser);
+        END;
+        CREATE TRIGGER scraped_urls_fts_au AFTER UPDATE ON scraped_urls BEGIN
+            INSERT INTO scraped_urls_fts(scraped_urls_fts, rowid, url, advertiser)
+            VALUES ('delete', old.id, old.url, old.advertiser);
+            INSERT INTO scraped_urls_fts(rowid, url, advertiser)
+            VALUES (new.id, new.url, new.advertiser);
+        END;
+
+        INSERT INTO processed_urls_fts(rowid, url, source, error_message, details)
+        SELECT id, url, source, error_message, details FROM processed_urls;
+        INSERT INTO scraped_urls_fts(rowid, url, advertiser)
+        SELECT id, url, advertiser FROM scraped_urls;
+    "),
+    (3, "
+        CREATE TABLE quotas (
+            scope TEXT NOT NULL,
+            scope_value TEXT NOT NULL,
+            max_count INTEGER NOT NULL,
+            PRIMARY KEY (scope, scope_value)
+        );
+        CREATE TABLE quota_usage (
+            scope TEXT NOT NULL,
+            scope_value TEXT NOT NULL,
+            used_count INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (scope, scope_value)
+        );
+    "),
+    (4, "
+        CREATE TABLE workload_results (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            started_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            duration_ms INTEGER NOT NULL,
+            urls_total INTEGER NOT NULL,
+            urls_success INTEGER NOT NULL,
+            success_rate REAL NOT NULL,
+            total_cost REAL NOT NULL,
+            total_tokens INTEGER NOT NULL,
+            urls_per_sec REAL NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_workload_results_name ON workload_results(name, started_at);
+    "),
+    (5, "
+        ALTER TABLE scraped_urls ADD COLUMN metadata TEXT;
+    "),
+    (6, "
+        ALTER TABLE scraped_urls ADD COLUMN enrich_attempts INTEGER NOT NULL DEFAULT 0;
+        ALTER TABLE scraped_urls ADD COLUMN enrich_failed_at TEXT;
+    "),
+    (7, "
+        ALTER TABLE processed_urls ADD COLUMN screenshot_path TEXT;
+        ALTER TABLE processed_urls ADD COLUMN confirmation_data TEXT;
+        ALTER TABLE processed_urls ADD COLUMN network_data TEXT;
+    "),
+];
+
+fn run_migrations(conn: &mut Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            applied_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        );"
+    )?;
+
+    let mut current_version: u32 = conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_migrations",
+        [],
+        |row| row.get::<_, i64>(0)
+    )? as u32;
+
+    // Databases upgraded by the old column-sniffing migrate_database already
+    // have the columns migration #1 would add; treat that as already applied
+    // rather than re-running an ALTER TABLE that would fail.
+    if current_version == 0 && conn.prepare("SELECT error_category, details FROM processed_urls LIMIT 1").is_ok() {
+        conn.execute("INSERT OR IGNORE INTO schema_migrations (version) VALUES (1)", [])?;
+        current_version = 1;
     }
-    
-    // Check if details column exists
-    let has_details: bool = conn
-        .prepare("SELECT details FROM processed_urls LIMIT 1")
-        .is_ok();
-    
-    if !has_details {
-        // Add details column
-        conn.execute("ALTER TABLE processed_urls ADD COLUMN details TEXT", [])?;
+
+    for &(version, up_sql) in MIGRATIONS {
+        if version <= current_version {
+            continue;
+        }
+        let tx = conn.transaction()?;
+        tx.execute_batch(up_sql)?;
+        tx.execute("INSERT INTO schema_migrations (version) VALUES (?)", [version])?;
+        tx.commit()?;
     }
-    
-    // Now create the index on error_category (only if column exists)
-    conn.execute(
-        "CREATE INDEX IF NOT EXISTS idx_processed_category ON processed_urls(error_category)",
-        []
-    )?;
-    
+
     Ok(())
 }
 
 // ==================== PROCESSED URLs ====================
 
-pub fn get_processed_urls(db_path: &str, limit: i32) -> Result<Vec<ProcessedURL>> {
-    let conn = Connection::open(db_path)?;
-    
-    let mut stmt = conn.prepare(
-        "SELECT id, url, source, status, fields_filled, error_message, error_category, details, processed_at 
-         FROM processed_urls 
-         ORDER BY processed_at DESC 
-         LIMIT ?"
-    )?;
-    
-    let rows = stmt.query_map([limit], |row| {
-        Ok(ProcessedURL {
-            id: row.get(0)?,
-            url: row.get(1)?,
-            source: row.get(2)?,
-            status: row.get(3)?,
-            fields_filled: row.get(4)?,
-            error_message: row.get(5)?,
-            error_category: row.get(6)?,
-            details: row.get(7)?,
-            processed_at: row.get(8)?,
-        })
-    })?;
-    
-    let mut results = Vec::new();
-    for row in rows {
-        results.push(row?);
-    }
-    Ok(results)
+pub fn get_processed_urls(pool: &DbPool, limit: i32) -> Result<Vec<ProcessedURL>> {
+    let conn = pool.get()?;
+    query_all(
+        &conn,
+        "SELECT id, url, source, status, fields_filled, error_message, error_category, details,
+                screenshot_path, confirmation_data, network_data, processed_at
+         FROM processed_urls
+         ORDER BY processed_at DESC
+         LIMIT ?",
+        [limit]
+    )
 }
 
-pub fn get_processed_stats(db_path: &str) -> Result<ProcessedStats> {
-    let conn = Connection::open(db_path)?;
-    
+pub fn get_processed_stats(pool: &DbPool) -> Result<ProcessedStats> {
+    let conn = pool.get()?;
+
     let total: i32 = conn.query_row("SELECT COUNT(*) FROM processed_urls", [], |row| row.get(0))?;
     let successful: i32 = conn.query_row("SELECT COUNT(*) FROM processed_urls WHERE status = 'success'", [], |row| row.get(0))?;
     let failed: i32 = conn.query_row("SELECT COUNT(*) FROM processed_urls WHERE status = 'failed'", [], |row| row.get(0))?;
     let skipped: i32 = conn.query_row("SELECT COUNT(*) FROM processed_urls WHERE status = 'skipped'", [], |row| row.get(0))?;
-    
+
     Ok(ProcessedStats { total, successful, failed, skipped })
 }
 
-pub fn delete_processed_url(db_path: &str, id: i32) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn delete_processed_url(pool: &DbPool, id: i32) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute("DELETE FROM processed_urls WHERE id = ?", [id])?;
     Ok(())
 }
 
-pub fn clear_processed_urls(db_path: &str) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn clear_processed_urls(pool: &DbPool) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute("DELETE FROM processed_urls", [])?;
     Ok(())
 }
 
 /// Reset failed URLs so they can be retried
 /// Returns the number of URLs reset
-pub fn retry_failed_urls(db_path: &str) -> Result<usize> {
-    let conn = Connection::open(db_path)?;
+pub fn retry_failed_urls(pool: &DbPool) -> Result<usize> {
+    let conn = pool.get()?;
     let count = conn.execute("DELETE FROM processed_urls WHERE status = 'failed'", [])?;
     Ok(count)
 }
 
 /// Reset a specific URL by ID so it can be retried
-pub fn retry_url_by_id(db_path: &str, id: i32) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn retry_url_by_id(pool: &DbPool, id: i32) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute("DELETE FROM processed_urls WHERE id = ?", [id])?;
     Ok(())
 }
 
 /// Get count of failed URLs
-pub fn get_failed_count(db_path: &str) -> Result<i32> {
-    let conn = Connection::open(db_path)?;
+pub fn get_failed_count(pool: &DbPool) -> Result<i32> {
+    let conn = pool.get()?;
     let count: i32 = conn.query_row(
         "SELECT COUNT(*) FROM processed_urls WHERE status = 'failed'",
         [],
@@ -169,15 +420,15 @@ pub fn get_failed_count(db_path: &str) -> Result<i32> {
     Ok(count)
 }
 
-pub fn export_processed_csv(db_path: &str) -> Result<String> {
-    let conn = Connection::open(db_path)?;
-    
+pub fn export_processed_csv(pool: &DbPool) -> Result<String> {
+    let conn = pool.get()?;
+
     let mut stmt = conn.prepare(
         "SELECT id, url, source, status, processed_at FROM processed_urls ORDER BY processed_at DESC"
     )?;
-    
+
     let mut csv = String::from("id,url,source,status,processed_at\n");
-    
+
     let rows = stmt.query_map([], |row| {
         Ok((
             row.get::<_, i32>(0)?,
@@ -187,64 +438,83 @@ pub fn export_processed_csv(db_path: &str) -> Result<String> {
             row.get::<_, String>(4)?,
         ))
     })?;
-    
+
     for row in rows {
         let (id, url, source, status, processed_at) = row?;
         let escaped_url = url.replace('"', "\"\"");
         csv.push_str(&format!("{},\"{}\",{},{},{}\n", id, escaped_url, source, status, processed_at));
     }
-    
+
     Ok(csv)
 }
 
 // ==================== SCRAPED URLs ====================
 
-pub fn get_scraped_urls(db_path: &str, limit: i32) -> Result<Vec<ScrapedURL>> {
-    let conn = Connection::open(db_path)?;
-    
-    let mut stmt = conn.prepare(
-        "SELECT id, url, ad_id, advertiser, scraped_at, processed 
-         FROM scraped_urls 
-         ORDER BY scraped_at DESC 
-         LIMIT ?"
+pub fn get_scraped_urls(pool: &DbPool, limit: i32) -> Result<Vec<ScrapedURL>> {
+    let conn = pool.get()?;
+    query_all(
+        &conn,
+        "SELECT id, url, ad_id, advertiser, scraped_at, processed, metadata
+         FROM scraped_urls
+         ORDER BY scraped_at DESC
+         LIMIT ?",
+        [limit]
+    )
+}
+
+/// Rows still missing enrichment, never-attempted rows first so a handful of
+/// permanently-unreachable old URLs can't crowd out newer ones once they've
+/// failed a few times - `enrich_all_pending` calls `record_enrich_failure` on
+/// every failed fetch to keep `enrich_attempts` current.
+pub fn get_scraped_urls_missing_metadata(pool: &DbPool, limit: i32) -> Result<Vec<ScrapedURL>> {
+    let conn = pool.get()?;
+    query_all(
+        &conn,
+        "SELECT id, url, ad_id, advertiser, scraped_at, processed, metadata
+         FROM scraped_urls
+         WHERE metadata IS NULL
+         ORDER BY enrich_attempts ASC, scraped_at ASC
+         LIMIT ?",
+        [limit]
+    )
+}
+
+pub fn set_scraped_url_metadata(pool: &DbPool, id: i32, metadata: &str) -> Result<()> {
+    let conn = pool.get()?;
+    conn.execute("UPDATE scraped_urls SET metadata = ? WHERE id = ?", params![metadata, id])?;
+    Ok(())
+}
+
+/// Bumps `enrich_attempts` and stamps `enrich_failed_at` after a failed
+/// fetch/parse, so the next `get_scraped_urls_missing_metadata` call sorts
+/// this row behind rows that haven't failed yet.
+pub fn record_enrich_failure(pool: &DbPool, id: i32) -> Result<()> {
+    let conn = pool.get()?;
+    conn.execute(
+        "UPDATE scraped_urls SET enrich_attempts = enrich_attempts + 1, enrich_failed_at = CURRENT_TIMESTAMP WHERE id = ?",
+        [id]
     )?;
-    
-    let rows = stmt.query_map([limit], |row| {
-        Ok(ScrapedURL {
-            id: row.get(0)?,
-            url: row.get(1)?,
-            ad_id: row.get(2)?,
-            advertiser: row.get(3)?,
-            scraped_at: row.get(4)?,
-            processed: row.get::<_, i32>(5)? == 1,
-        })
-    })?;
-    
-    let mut results = Vec::new();
-    for row in rows {
-        results.push(row?);
-    }
-    Ok(results)
+    Ok(())
 }
 
-pub fn get_scraped_stats(db_path: &str) -> Result<ScrapedStats> {
-    let conn = Connection::open(db_path)?;
-    
+pub fn get_scraped_stats(pool: &DbPool) -> Result<ScrapedStats> {
+    let conn = pool.get()?;
+
     let total: i32 = conn.query_row("SELECT COUNT(*) FROM scraped_urls", [], |row| row.get(0))?;
     let processed: i32 = conn.query_row("SELECT COUNT(*) FROM scraped_urls WHERE processed = 1", [], |row| row.get(0))?;
     let pending: i32 = conn.query_row("SELECT COUNT(*) FROM scraped_urls WHERE processed = 0", [], |row| row.get(0))?;
-    
+
     Ok(ScrapedStats { total, processed, pending })
 }
 
-pub fn delete_scraped_url(db_path: &str, id: i32) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn delete_scraped_url(pool: &DbPool, id: i32) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute("DELETE FROM scraped_urls WHERE id = ?", [id])?;
     Ok(())
 }
 
-pub fn update_scraped_url_status(db_path: &str, id: i32, processed: bool) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn update_scraped_url_status(pool: &DbPool, id: i32, processed: bool) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute(
         "UPDATE scraped_urls SET processed = ? WHERE id = ?",
         [if processed { 1 } else { 0 }, id]
@@ -252,21 +522,21 @@ pub fn update_scraped_url_status(db_path: &str, id: i32, processed: bool) -> Res
     Ok(())
 }
 
-pub fn clear_scraped_urls(db_path: &str) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn clear_scraped_urls(pool: &DbPool) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute("DELETE FROM scraped_urls", [])?;
     Ok(())
 }
 
-pub fn export_scraped_csv(db_path: &str) -> Result<String> {
-    let conn = Connection::open(db_path)?;
-    
+pub fn export_scraped_csv(pool: &DbPool) -> Result<String> {
+    let conn = pool.get()?;
+
     let mut stmt = conn.prepare(
         "SELECT id, url, ad_id, advertiser, scraped_at, processed FROM scraped_urls ORDER BY scraped_at DESC"
     )?;
-    
+
     let mut csv = String::from("id,url,ad_id,advertiser,scraped_at,processed\n");
-    
+
     let rows = stmt.query_map([], |row| {
         Ok((
             row.get::<_, i32>(0)?,
@@ -277,7 +547,7 @@ pub fn export_scraped_csv(db_path: &str) -> Result<String> {
             row.get::<_, i32>(5)?,
         ))
     })?;
-    
+
     for row in rows {
         let (id, url, ad_id, advertiser, scraped_at, processed) = row?;
         let escaped_url = url.replace('"', "\"\"");
@@ -285,15 +555,223 @@ pub fn export_scraped_csv(db_path: &str) -> Result<String> {
         let adv = advertiser.unwrap_or_default().replace('"', "\"\"");
         csv.push_str(&format!("{},\"{}\",{},\"{}\",{},{}\n", id, escaped_url, ad, adv, scraped_at, processed));
     }
-    
+
     Ok(csv)
 }
 
+// ==================== INGESTION QUOTAS ====================
+
+/// Checks the `scope`/`scope_value` pair against its configured quota (if
+/// any) and bumps the usage counter, all within the caller's transaction so
+/// the check-then-increment is atomic with the row insert it guards.
+fn check_and_bump_quota(tx: &rusqlite::Transaction, scope: &str, scope_value: &str) -> Result<()> {
+    let max_count: Option<i64> = tx.query_row(
+        "SELECT max_count FROM quotas WHERE scope = ?1 AND scope_value = ?2",
+        params![scope, scope_value],
+        |row| row.get(0),
+    ).ok();
+
+    if let Some(max_count) = max_count {
+        let used: i64 = tx.query_row(
+            "SELECT used_count FROM quota_usage WHERE scope = ?1 AND scope_value = ?2",
+            params![scope, scope_value],
+            |row| row.get(0),
+        ).unwrap_or(0);
+
+        if used >= max_count {
+            return Err(DbError::QuotaExceeded {
+                scope: scope.to_string(),
+                scope_value: scope_value.to_string(),
+                max_count,
+            });
+        }
+    }
+
+    tx.execute(
+        "INSERT INTO quota_usage (scope, scope_value, used_count) VALUES (?1, ?2, 1)
+         ON CONFLICT(scope, scope_value) DO UPDATE SET used_count = used_count + 1",
+        params![scope, scope_value],
+    )?;
+
+    Ok(())
+}
+
+/// Inserts a scraped URL, rejecting it if its advertiser has hit its quota.
+pub fn insert_scraped_url(pool: &DbPool, url: &str, ad_id: Option<&str>, advertiser: Option<&str>) -> Result<i64> {
+    let mut conn = pool.get()?;
+    let tx = conn.transaction()?;
+
+    if let Some(advertiser) = advertiser {
+        check_and_bump_quota(&tx, "advertiser", advertiser)?;
+    }
+
+    tx.execute(
+        "INSERT INTO scraped_urls (url, ad_id, advertiser) VALUES (?1, ?2, ?3)",
+        params![url, ad_id, advertiser],
+    )?;
+    let id = tx.last_insert_rowid();
+    tx.commit()?;
+    Ok(id)
+}
+
+/// Records a processed URL, rejecting it if its source has hit its quota.
+pub fn record_processed_url(pool: &DbPool, url: &str, source: &str, status: &str) -> Result<i64> {
+    let mut conn = pool.get()?;
+    let tx = conn.transaction()?;
+
+    check_and_bump_quota(&tx, "source", source)?;
+
+    tx.execute(
+        "INSERT INTO processed_urls (url, source, status) VALUES (?1, ?2, ?3)",
+        params![url, source, status],
+    )?;
+    let id = tx.last_insert_rowid();
+    tx.commit()?;
+    Ok(id)
+}
+
+pub fn set_quota(pool: &DbPool, scope: &str, scope_value: &str, max_count: i64) -> Result<()> {
+    let conn = pool.get()?;
+    conn.execute(
+        "INSERT INTO quotas (scope, scope_value, max_count) VALUES (?1, ?2, ?3)
+         ON CONFLICT(scope, scope_value) DO UPDATE SET max_count = excluded.max_count",
+        params![scope, scope_value, max_count],
+    )?;
+    Ok(())
+}
+
+pub fn get_quota_usage(pool: &DbPool) -> Result<Vec<(String, String, i64, i64)>> {
+    let conn = pool.get()?;
+    let mut stmt = conn.prepare(
+        "SELECT q.scope, q.scope_value, COALESCE(u.used_count, 0), q.max_count
+         FROM quotas q
+         LEFT JOIN quota_usage u ON u.scope = q.scope AND u.scope_value = q.scope_value
+         ORDER BY q.scope, q.scope_value"
+    )?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+    })?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+    Ok(results)
+}
+
+/// Recomputes quota counters from the base tables. Counters can drift if
+/// rows are deleted directly (e.g. `clear_scraped_urls`), so this is an
+/// offline repair routine rather than something run on every insert.
+pub fn recount_quotas(pool: &DbPool) -> Result<()> {
+    let mut conn = pool.get()?;
+    let tx = conn.transaction()?;
+
+    tx.execute("DELETE FROM quota_usage", [])?;
+    tx.execute(
+        "INSERT INTO quota_usage (scope, scope_value, used_count)
+         SELECT 'advertiser', advertiser, COUNT(*) FROM scraped_urls
+         WHERE advertiser IS NOT NULL GROUP BY advertiser",
+        [],
+    )?;
+    tx.execute(
+        "INSERT INTO quota_usage (scope, scope_value, used_count)
+         SELECT 'source', source, COUNT(*) FROM processed_urls
+         GROUP BY source",
+        [],
+    )?;
+
+    tx.commit()?;
+    Ok(())
+}
+
+// ==================== FULL-TEXT SEARCH ====================
+
+/// Turns a free-text query into an FTS5 prefix query by appending `*` to
+/// each whitespace-separated token, so "exam" matches "example.com".
+fn fts_prefix_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|token| format!("{}*", token.replace('"', "")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn search_processed(pool: &DbPool, query: &str, limit: i32) -> Result<Vec<ProcessedURL>> {
+    let conn = pool.get()?;
+    let fts_query = fts_prefix_query(query);
+
+    let mut stmt = conn.prepare(
+        "SELECT p.id, p.url, p.source, p.status, p.fields_filled, p.error_message, p.error_category, p.details,
+                p.screenshot_path, p.confirmation_data, p.network_data, p.processed_at
+         FROM processed_urls_fts f
+         JOIN processed_urls p ON p.id = f.rowid
+         WHERE processed_urls_fts MATCH ?1
+         ORDER BY bm25(processed_urls_fts)
+         LIMIT ?2"
+    )?;
+
+    let rows = stmt.query_map(params![fts_query, limit], |row| {
+        Ok(ProcessedURL {
+            id: row.get(0)?,
+            url: row.get(1)?,
+            source: row.get(2)?,
+            status: row.get(3)?,
+            fields_filled: row.get(4)?,
+            error_message: row.get(5)?,
+            error_category: row.get(6)?,
+            details: row.get(7)?,
+            screenshot_path: row.get(8)?,
+            confirmation_data: row.get(9)?,
+            network_data: row.get(10)?,
+            processed_at: row.get(11)?,
+        })
+    })?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+    Ok(results)
+}
+
+pub fn search_scraped(pool: &DbPool, query: &str, limit: i32) -> Result<Vec<ScrapedURL>> {
+    let conn = pool.get()?;
+    let fts_query = fts_prefix_query(query);
+
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.url, s.ad_id, s.advertiser, s.scraped_at, s.processed, s.metadata
+         FROM scraped_urls_fts f
+         JOIN scraped_urls s ON s.id = f.rowid
+         WHERE scraped_urls_fts MATCH ?1
+         ORDER BY bm25(scraped_urls_fts)
+         LIMIT ?2"
+    )?;
+
+    let rows = stmt.query_map(params![fts_query, limit], |row| {
+        Ok(ScrapedURL {
+            id: row.get(0)?,
+            url: row.get(1)?,
+            ad_id: row.get(2)?,
+            advertiser: row.get(3)?,
+            scraped_at: row.get(4)?,
+            processed: row.get::<_, i32>(5)? == 1,
+            metadata: row.get(6)?,
+        })
+    })?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row?);
+    }
+    Ok(results)
+}
+
 // ==================== LEGACY COMPATIBILITY ====================
 // Keep old function names working for existing code
 
-pub fn is_url_processed(db_path: &str, url: &str) -> Result<bool> {
-    let conn = Connection::open(db_path)?;
+pub fn is_url_processed(pool: &DbPool, url: &str) -> Result<bool> {
+    let conn = pool.get()?;
     let count: i32 = conn.query_row(
         "SELECT COUNT(*) FROM processed_urls WHERE url = ?",
         [url],
@@ -303,8 +781,8 @@ pub fn is_url_processed(db_path: &str, url: &str) -> Result<bool> {
 }
 
 // Legacy stats function - now returns processed stats
-pub fn get_stats(db_path: &str) -> Result<ProcessedStats> {
-    get_processed_stats(db_path)
+pub fn get_stats(pool: &DbPool) -> Result<ProcessedStats> {
+    get_processed_stats(pool)
 }
 
 // ==================== API COST TRACKING ====================
@@ -312,37 +790,20 @@ pub fn get_stats(db_path: &str) -> Result<ProcessedStats> {
 use crate::commands::{ApiSession, ApiCostSummary, ModelCostStats};
 use std::collections::HashMap;
 
-pub fn get_api_sessions(db_path: &str, limit: i32) -> Result<Vec<ApiSession>> {
-    let conn = Connection::open(db_path)?;
-
-    let mut stmt = conn.prepare(
+pub fn get_api_sessions(pool: &DbPool, limit: i32) -> Result<Vec<ApiSession>> {
+    let conn = pool.get()?;
+    query_all(
+        &conn,
         "SELECT id, session_start, model, input_tokens, output_tokens, cost, api_calls
          FROM api_sessions
          ORDER BY session_start DESC
-         LIMIT ?"
-    )?;
-
-    let rows = stmt.query_map([limit], |row| {
-        Ok(ApiSession {
-            id: row.get(0)?,
-            session_start: row.get(1)?,
-            model: row.get(2)?,
-            input_tokens: row.get(3)?,
-            output_tokens: row.get(4)?,
-            cost: row.get(5)?,
-            api_calls: row.get(6)?,
-        })
-    })?;
-
-    let mut sessions = Vec::new();
-    for session in rows {
-        sessions.push(session?);
-    }
-    Ok(sessions)
+         LIMIT ?",
+        [limit]
+    )
 }
 
-pub fn get_api_cost_summary(db_path: &str) -> Result<ApiCostSummary> {
-    let conn = Connection::open(db_path)?;
+pub fn get_api_cost_summary(pool: &DbPool) -> Result<ApiCostSummary> {
+    let conn = pool.get()?;
 
     // Get totals by model
     let mut stmt = conn.prepare(
@@ -406,8 +867,54 @@ pub fn get_api_cost_summary(db_path: &str) -> Result<ApiCostSummary> {
     })
 }
 
-pub fn clear_api_sessions(db_path: &str) -> Result<()> {
-    let conn = Connection::open(db_path)?;
+pub fn clear_api_sessions(pool: &DbPool) -> Result<()> {
+    let conn = pool.get()?;
     conn.execute("DELETE FROM api_sessions", [])?;
     Ok(())
 }
+
+// ==================== WORKLOAD RESULTS ====================
+
+/// The most recent prior result for a workload `name`, used as the
+/// regression baseline for the next run of the same workload.
+pub struct WorkloadBaseline {
+    pub success_rate: f64,
+    pub total_cost: f64,
+}
+
+/// Fetches the latest recorded run for `name`, if any, to compare a new run
+/// against. Called before `record_workload_result` so the new run's own row
+/// never counts as its own baseline.
+pub fn get_workload_baseline(pool: &DbPool, name: &str) -> Result<Option<WorkloadBaseline>> {
+    let conn = pool.get()?;
+    conn.query_row(
+        "SELECT success_rate, total_cost FROM workload_results
+         WHERE name = ? ORDER BY started_at DESC LIMIT 1",
+        [name],
+        |row| Ok(WorkloadBaseline { success_rate: row.get(0)?, total_cost: row.get(1)? }),
+    )
+    .map(Some)
+    .or_else(|e| if matches!(e, rusqlite::Error::QueryReturnedNoRows) { Ok(None) } else { Err(e.into()) })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn record_workload_result(
+    pool: &DbPool,
+    name: &str,
+    duration_ms: i64,
+    urls_total: i32,
+    urls_success: i32,
+    success_rate: f64,
+    total_cost: f64,
+    total_tokens: i64,
+    urls_per_sec: f64,
+) -> Result<()> {
+    let conn = pool.get()?;
+    conn.execute(
+        "INSERT INTO workload_results
+         (name, duration_ms, urls_total, urls_success, success_rate, total_cost, total_tokens, urls_per_sec)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        params![name, duration_ms, urls_total, urls_success, success_rate, total_cost, total_tokens, urls_per_sec],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,174 @@
+//! Unattended `--headless` entry point, invoked from `main` before the Tauri
+//! `Builder` is ever constructed so a scheduled cron run never needs a
+//! display. Reuses `db` and the sidecar/venv resolution helpers in
+//! `commands`, but can't use the embedded-CPython bootstrap fallback
+//! (`provision_embedded_python`) since that needs a Tauri resource resolver -
+//! headless runs are expected to use a release build's bundled sidecar, or an
+//! already-provisioned dev venv.
+
+use crate::commands::{self, BotConfig};
+use crate::db;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Parsed `--headless` flags. Returned by `parse_args`; its absence means
+/// `main` should fall through to the normal GUI path.
+pub struct HeadlessArgs {
+    pub run_once: bool,
+    pub duration_mins: Option<u64>,
+}
+
+/// Hand-rolled parsing for the handful of headless flags - not worth pulling
+/// in an arg-parsing crate for three options. Returns `None` (meaning "run
+/// the GUI as usual") unless `--headless` is present.
+pub fn parse_args(args: &[String]) -> Option<HeadlessArgs> {
+    if !args.iter().any(|a| a == "--headless") {
+        return None;
+    }
+
+    let run_once = args.iter().any(|a| a == "--run-once");
+    let duration_mins = args
+        .iter()
+        .position(|a| a == "--duration")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse::<u64>().ok());
+
+    Some(HeadlessArgs { run_once, duration_mins })
+}
+
+/// Runs the scrape/process loop to completion and returns the process exit
+/// code, without ever building a `tauri::Builder` or opening a window.
+pub fn run(data_dir: PathBuf, args: HeadlessArgs) -> i32 {
+    if let Err(e) = std::fs::create_dir_all(&data_dir) {
+        eprintln!("❌ Failed to create app data directory: {}", e);
+        return 1;
+    }
+
+    if let Err(e) = db::init_database(&data_dir.join("inboxhunter.db")) {
+        eprintln!("❌ Failed to initialize database: {}", e);
+        return 1;
+    }
+
+    let config = match load_settings_from_disk(&data_dir) {
+        Ok(Some(config)) => config,
+        Ok(None) => {
+            eprintln!("❌ No saved settings found. Save settings from the GUI at least once before running --headless.");
+            return 1;
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to load settings: {}", e);
+            return 1;
+        }
+    };
+
+    match run_bot_to_completion(&data_dir, &config, &args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("❌ Headless run failed: {}", e);
+            1
+        }
+    }
+}
+
+/// Reads `settings.json` the same way `commands::load_settings` does, minus
+/// the `AppHandle` plumbing headless mode deliberately avoids.
+fn load_settings_from_disk(data_dir: &Path) -> Result<Option<BotConfig>, String> {
+    let settings_path = data_dir.join("settings.json");
+    if !settings_path.exists() {
+        return Ok(None);
+    }
+
+    let config_json = std::fs::read_to_string(&settings_path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&config_json).map(Some).map_err(|e| e.to_string())
+}
+
+fn run_bot_to_completion(data_dir: &Path, config: &BotConfig, args: &HeadlessArgs) -> Result<(), String> {
+    let config_path = data_dir.join("bot_config.json");
+    let config_json = serde_json::to_string(config).map_err(|e| e.to_string())?;
+    std::fs::write(&config_path, &config_json).map_err(|e| e.to_string())?;
+
+    let mut cmd_args = vec!["--config".to_string(), config_path.to_string_lossy().to_string()];
+    if config.settings.debug {
+        cmd_args.push("--debug".to_string());
+    }
+    if config.settings.headless {
+        cmd_args.push("--headless".to_string());
+    }
+    if args.run_once {
+        cmd_args.push("--run-once".to_string());
+    }
+    if let Some(mins) = args.duration_mins {
+        cmd_args.push("--duration-mins".to_string());
+        cmd_args.push(mins.to_string());
+    }
+
+    let sidecar_path = commands::find_sidecar_binary_manual();
+    let automation_path = commands::get_automation_path();
+
+    let mut child = if let Some(automation_path) = automation_path.filter(|_| sidecar_path.is_none()) {
+        let python_cmd = commands::find_dev_venv_python(&automation_path)
+            .or_else(commands::find_system_python)
+            .ok_or("No Python interpreter found. Run `cd automation && python3 -m venv venv && source venv/bin/activate && pip install -r requirements.txt && playwright install chromium`, or use a release build with the bundled sidecar.")?;
+
+        let main_script = automation_path.join("main.py");
+        let mut full_args = vec![main_script.to_string_lossy().to_string()];
+        full_args.extend(cmd_args);
+
+        println!("🐍 Running headless with Python automation scripts");
+        println!("   Python: {}", python_cmd);
+        println!("   Script: {}", main_script.display());
+
+        spawn_bot_process(Command::new(&python_cmd).args(&full_args).current_dir(&automation_path))?
+    } else if let Some(sidecar_path) = sidecar_path {
+        println!("📦 Running headless with sidecar binary: {}", sidecar_path.display());
+        spawn_bot_process(Command::new(&sidecar_path).args(&cmd_args))?
+    } else {
+        return Err("No sidecar binary or automation scripts found.".to_string());
+    };
+
+    let stdout_handle = child.stdout.take().map(|stdout| {
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().flatten() {
+                println!("{}", line);
+            }
+        })
+    });
+    let stderr_handle = child.stderr.take().map(|stderr| {
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().flatten() {
+                eprintln!("{}", line);
+            }
+        })
+    });
+
+    let status = child.wait().map_err(|e| format!("Failed to wait for bot process: {}", e))?;
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    if let Some(handle) = stderr_handle {
+        let _ = handle.join();
+    }
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("Bot process exited with {}", status))
+    }
+}
+
+fn spawn_bot_process(cmd: &mut Command) -> Result<std::process::Child, String> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .env("PYTHONIOENCODING", "utf-8")
+        .env("PYTHONUTF8", "1");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    cmd.spawn().map_err(|e| format!("Failed to start bot: {}", e))
+}
@@ -3,10 +3,21 @@
 
 mod commands;
 mod db;
+mod headless;
+mod metrics;
 
 use tauri::Manager;
 
 fn main() {
+    let context = tauri::generate_context!();
+
+    let cli_args: Vec<String> = std::env::args().collect();
+    if let Some(headless_args) = headless::parse_args(&cli_args) {
+        let data_dir = tauri::api::path::app_data_dir(context.config())
+            .expect("Failed to get app data directory");
+        std::process::exit(headless::run(data_dir, headless_args));
+    }
+
     tauri::Builder::default()
         .setup(|app| {
             // Initialize database
@@ -18,29 +29,52 @@ fn main() {
             std::fs::create_dir_all(&data_dir).ok();
             
             let db_path = data_dir.join("inboxhunter.db");
-            db::init_database(&db_path).expect("Failed to initialize database");
-            
-            // Store database path in app state
+            let db_pool = db::init_database(&db_path).expect("Failed to initialize database");
+
+            // Store the pooled connection in app state
             app.manage(commands::AppState {
-                db_path: std::sync::Mutex::new(db_path.to_string_lossy().to_string()),
+                db_pool,
                 bot_running: std::sync::Mutex::new(false),
                 bot_process: std::sync::Mutex::new(None),
+                log_history: std::sync::Mutex::new(std::collections::VecDeque::new()),
+                stop_acked: std::sync::atomic::AtomicBool::new(false),
+                log_counters: metrics::LogCounters::default(),
+                search_strings: std::sync::Mutex::new(std::collections::HashMap::new()),
             });
-            
+
+            metrics::maybe_spawn_metrics_server(app_handle.clone());
+
+            // Check for an update in the background so startup isn't blocked
+            // on the release endpoint; a no-op if updateSettings isn't configured.
+            let update_check_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = commands::check_for_update(update_check_handle).await {
+                    log::warn!("⚠️  Update check failed: {}", e);
+                }
+            });
+
             println!("InboxHunter initialized. Data directory: {:?}", data_dir);
-            
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
+            commands::setup_python_environment,
+            commands::run_diagnostics,
             commands::start_bot,
             commands::stop_bot,
             commands::get_bot_status,
+            commands::pause_bot,
+            commands::resume_bot,
+            commands::request_bot_status,
+            commands::set_bot_concurrency,
+            commands::run_workload,
             // Processed URLs
             commands::get_processed_urls,
             commands::get_processed_stats,
             commands::delete_processed_url,
             commands::clear_processed_urls,
             commands::export_processed_csv,
+            commands::search_processed,
             // Scraped URLs
             commands::get_scraped_urls,
             commands::get_scraped_stats,
@@ -48,10 +82,32 @@ fn main() {
             commands::update_scraped_url_status,
             commands::clear_scraped_urls,
             commands::export_scraped_csv,
+            commands::search_scraped,
+            commands::enrich_scraped_url,
+            commands::enrich_all_pending,
+            commands::get_prometheus_metrics,
+            // Ingestion quotas
+            commands::set_quota,
+            commands::get_quota_usage,
+            commands::recount_quotas,
+            // Search state
+            commands::store_search_string,
+            commands::get_stored_search_string,
+            // System shell
+            commands::open_external,
+            commands::reveal_export,
+            // Secrets
+            commands::set_secret,
+            commands::get_secret,
             // Settings
             commands::save_settings,
             commands::load_settings,
+            // Auto-updater
+            commands::check_for_update,
+            commands::install_update,
+            commands::get_rate_limit_status,
+            commands::submit_logs,
         ])
-        .run(tauri::generate_context!())
+        .run(context)
         .expect("error while running tauri application");
 }
@@ -0,0 +1,136 @@
+use crate::commands::AppState;
+use crate::db::{self, DbPool};
+use std::fmt::Write;
+use std::io::{BufRead, BufReader, Write as IoWrite};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Manager};
+
+/// Real-time counters that `spawn_log_reader` bumps as it classifies each
+/// sidecar stdout log line, so `/metrics` can report run progress without a
+/// DB hit. Lines the sidecar writes to stderr are not counted.
+#[derive(Default)]
+pub struct LogCounters {
+    pub success: AtomicU64,
+    pub error: AtomicU64,
+}
+
+impl LogCounters {
+    pub fn record(&self, level: &str) {
+        match level {
+            "success" => {
+                self.success.fetch_add(1, Ordering::Relaxed);
+            }
+            "error" => {
+                self.error.fetch_add(1, Ordering::Relaxed);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Renders the crate's existing aggregates (`get_processed_stats`,
+/// `get_scraped_stats`, `get_api_cost_summary`) plus live bot/log state as
+/// Prometheus text-exposition format. Each aggregate is read by its own
+/// query, possibly on a different pooled connection, so the gauges are not
+/// one consistent snapshot: counts can change between those queries.
+pub fn render_metrics(pool: &DbPool, bot_running: bool, log_counters: &LogCounters) -> db::Result<String> {
+    let processed = db::get_processed_stats(pool)?;
+    let scraped = db::get_scraped_stats(pool)?;
+    let cost_summary = db::get_api_cost_summary(pool)?;
+
+    let mut out = String::new();
+
+    writeln!(out, "# HELP inboxhunter_processed_total Processed URLs by status").ok();
+    writeln!(out, "# TYPE inboxhunter_processed_total gauge").ok();
+    writeln!(out, "inboxhunter_processed_total{{status=\"success\"}} {}", processed.successful).ok();
+    writeln!(out, "inboxhunter_processed_total{{status=\"failed\"}} {}", processed.failed).ok();
+    writeln!(out, "inboxhunter_processed_total{{status=\"skipped\"}} {}", processed.skipped).ok();
+
+    writeln!(out, "# HELP inboxhunter_failed_total Processed URLs that ended in a failed status").ok();
+    writeln!(out, "# TYPE inboxhunter_failed_total gauge").ok();
+    writeln!(out, "inboxhunter_failed_total {}", processed.failed).ok();
+
+    writeln!(out, "# HELP inboxhunter_scraped_pending Scraped URLs not yet processed").ok();
+    writeln!(out, "# TYPE inboxhunter_scraped_pending gauge").ok();
+    writeln!(out, "inboxhunter_scraped_pending {}", scraped.pending).ok();
+    writeln!(out, "# HELP inboxhunter_scraped_total Total scraped URLs").ok();
+    writeln!(out, "# TYPE inboxhunter_scraped_total gauge").ok();
+    writeln!(out, "inboxhunter_scraped_total {}", scraped.total).ok();
+
+    writeln!(out, "# HELP inboxhunter_api_cost_usd_total API spend by model, in USD").ok();
+    writeln!(out, "# TYPE inboxhunter_api_cost_usd_total gauge").ok();
+    writeln!(out, "# HELP inboxhunter_api_tokens_total API token usage by model and kind").ok();
+    writeln!(out, "# TYPE inboxhunter_api_tokens_total gauge").ok();
+    for (model, stats) in &cost_summary.by_model {
+        writeln!(out, "inboxhunter_api_cost_usd_total{{model=\"{}\"}} {}", model, stats.cost).ok();
+        writeln!(out, "inboxhunter_api_tokens_total{{model=\"{}\",kind=\"input\"}} {}", model, stats.input_tokens).ok();
+        writeln!(out, "inboxhunter_api_tokens_total{{model=\"{}\",kind=\"output\"}} {}", model, stats.output_tokens).ok();
+    }
+
+    writeln!(out, "# HELP inboxhunter_bot_running Whether the bot process is currently running").ok();
+    writeln!(out, "# TYPE inboxhunter_bot_running gauge").ok();
+    writeln!(out, "inboxhunter_bot_running {}", if bot_running { 1 } else { 0 }).ok();
+
+    writeln!(out, "# HELP inboxhunter_log_events_total Sidecar log lines classified by spawn_log_reader").ok();
+    writeln!(out, "# TYPE inboxhunter_log_events_total counter").ok();
+    writeln!(out, "inboxhunter_log_events_total{{level=\"success\"}} {}", log_counters.success.load(Ordering::Relaxed)).ok();
+    writeln!(out, "inboxhunter_log_events_total{{level=\"error\"}} {}", log_counters.error.load(Ordering::Relaxed)).ok();
+
+    Ok(out)
+}
+
+fn handle_metrics_request(mut stream: std::net::TcpStream, app: &AppHandle) {
+    let mut reader = match stream.try_clone() {
+        Ok(clone) => BufReader::new(clone),
+        Err(_) => return,
+    };
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+
+    let is_metrics_path = request_line.starts_with("GET /metrics ") || request_line.starts_with("GET /metrics\r");
+    let (status, body) = if is_metrics_path {
+        let state = app.state::<AppState>();
+        let bot_running = state.bot_running.lock().map(|guard| *guard).unwrap_or(false);
+        let body = render_metrics(&state.db_pool, bot_running, &state.log_counters).unwrap_or_default();
+        ("200 OK", body)
+    } else {
+        ("404 Not Found", String::new())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+/// Spawns a minimal blocking HTTP server on `127.0.0.1:<port>` serving the
+/// current metrics snapshot at `/metrics`, so existing monitoring stacks can
+/// scrape run progress instead of polling the DB over IPC. Opt-in via the
+/// `INBOXHUNTER_METRICS_PORT` environment variable - unset by default.
+pub fn maybe_spawn_metrics_server(app: AppHandle) {
+    let port: u16 = match std::env::var("INBOXHUNTER_METRICS_PORT").ok().and_then(|v| v.parse().ok()) {
+        Some(port) => port,
+        None => return,
+    };
+
+    std::thread::spawn(move || {
+        let listener = match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(listener) => listener,
+            Err(e) => {
+                log::error!("❌ Failed to bind metrics server on 127.0.0.1:{}: {}", port, e);
+                return;
+            }
+        };
+        log::info!("📊 Metrics server listening on http://127.0.0.1:{}/metrics", port);
+
+        for stream in listener.incoming().flatten() {
+            handle_metrics_request(stream, &app);
+        }
+    });
+}